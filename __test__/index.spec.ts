@@ -1,9 +1,58 @@
+import { mkdtempSync, readFileSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
 import { join } from 'path'
 import { fileURLToPath } from 'url'
+import { gzipSync } from 'zlib'
 
 import test from 'ava'
 
-import { col, SessionContext } from '../index'
+import {
+  abs,
+  arrayAppend,
+  arrayContains,
+  arrayElement,
+  arrayLength,
+  ArrowIpcFormat,
+  binaryExpr,
+  caseExpr,
+  col,
+  currentTime,
+  dateBin,
+  datePart,
+  dateTrunc,
+  Expr,
+  FileCompressionType,
+  fromUnixtime,
+  interval,
+  JoinType,
+  lag,
+  lit,
+  litDecimal128,
+  litInt32,
+  litTyped,
+  lpad,
+  makeArray,
+  notExists,
+  Operator,
+  outRefCol,
+  power,
+  ParquetCompression,
+  rank,
+  regexpReplace,
+  ReadableStreamFormat,
+  round,
+  rowNumber,
+  scalarSubquery,
+  SessionContext,
+  splitPart,
+  struct,
+  sum,
+  TimeUnit,
+  TypeId,
+  upper,
+  when,
+  WindowFrameUnits,
+} from '../index'
 
 // https://github.com/apache/arrow-datafusion/blob/main/datafusion/core/tests/data/customer.csv
 const fixture = join(fileURLToPath(import.meta.url), '..', 'example.csv')
@@ -17,3 +66,444 @@ test('basic data operator', async (t) => {
     .show()
   t.pass()
 })
+
+test('collect rows', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  t.deepEqual(await df.collect(), [{ a: 1, b: 2, c: 3 }])
+  t.deepEqual(await df.collect({ int64AsBigInt: true }), [{ a: 1n, b: 2n, c: 3n }])
+})
+
+test('collect columns', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const { columns, validity } = await df.collectColumns()
+  t.deepEqual(columns.a, new BigInt64Array([1n]))
+  t.is(validity.a, null)
+  const rows = Array.from({ length: 10 }, (_, i) => ({
+    id: i,
+    name: `n${i}`,
+    active: i % 2 === 0,
+    score: i === 1 || i === 9 ? null : i,
+    at: new Date(i * 1000),
+  }))
+  ctx.registerObjects('rows', rows)
+  const sql = "SELECT id, name, active, score, at, CAST('1970-01-02' AS DATE) AS day FROM rows ORDER BY id"
+  const nullable = await (await ctx.sql(sql)).collectColumns()
+  t.deepEqual(nullable.columns.name, ['n0', 'n1', 'n2', 'n3', 'n4', 'n5', 'n6', 'n7', 'n8', 'n9'])
+  t.deepEqual(nullable.columns.active, new Uint8Array([1, 0, 1, 0, 1, 0, 1, 0, 1, 0]))
+  t.deepEqual(nullable.columns.at, new BigInt64Array(rows.map((_, i) => BigInt(i * 1000))))
+  t.deepEqual(nullable.columns.day, new Int32Array(rows.map(() => 1)))
+  t.deepEqual(nullable.validity.score, new Uint8Array([0b11111101, 0b00000001]))
+  t.is(nullable.validity.id, null)
+})
+
+test('to arrow ipc', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const file = await df.toArrowIpc({ format: ArrowIpcFormat.File })
+  t.is(file.subarray(0, 6).toString(), 'ARROW1')
+  const stream = await df.toArrowIpc()
+  t.true(stream.length > 0)
+})
+
+test('register arrow ipc', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  ctx.registerArrowIpc('example', await df.toArrowIpc({ format: ArrowIpcFormat.File }))
+  t.deepEqual(await (await ctx.sql('SELECT a FROM example')).collect(), [{ a: 1 }])
+  t.deepEqual(await ctx.readArrowIpc(await df.toArrowIpc()).collect(), [{ a: 1, b: 2, c: 3 }])
+})
+
+test('register objects', async (t) => {
+  const ctx = new SessionContext()
+  ctx.registerObjects('users', [{ id: 1, name: 'Alice', score: 1.5 }, { id: 2, name: 'Bob' }])
+  t.deepEqual(await (await ctx.sql('SELECT name, score FROM users ORDER BY id')).collect(), [
+    { name: 'Alice', score: 1.5 },
+    { name: 'Bob', score: null },
+  ])
+  ctx.registerObjects('typed', [{ id: '1' }], {
+    schema: [{ name: 'id', dataType: { typeId: TypeId.Int32 } }],
+  })
+  t.deepEqual(await (await ctx.sql('SELECT id FROM typed')).collect(), [{ id: 1 }])
+  ctx.registerObjects('events', [{ at: new Date('2023-01-02T03:45:30Z') }])
+  t.deepEqual(await (await ctx.sql("SELECT date_part('day', at) AS day FROM events")).collect(), [{ day: 2 }])
+  t.throws(() => ctx.registerObjects('big', [{ id: 2n ** 64n }]))
+  ctx.registerObjects('huge', [{ id: 2 ** 63 }])
+  t.deepEqual(await (await ctx.sql('SELECT arrow_typeof(id) AS type FROM huge')).collect(), [{ type: 'Float64' }])
+})
+
+test('execute stream', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const batches = []
+  for await (const rows of df.executeStream()) {
+    batches.push(rows)
+  }
+  t.deepEqual(batches.flat(), [{ a: 1, b: 2, c: 3 }])
+})
+
+test('to readable stream', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const rows = []
+  for await (const row of df.toReadableStream()) {
+    rows.push(row)
+  }
+  t.deepEqual(rows, [{ a: 1, b: 2, c: 3 }])
+  const chunks = []
+  for await (const chunk of df.toReadableStream({ format: ReadableStreamFormat.Csv })) {
+    chunks.push(chunk)
+  }
+  t.is(Buffer.concat(chunks).toString(), 'a,b,c\n1,2,3\n')
+  const collectArrow = async (source: typeof df) => {
+    const buffers = []
+    for await (const chunk of source.toReadableStream({ format: ReadableStreamFormat.Arrow })) {
+      buffers.push(chunk)
+    }
+    return Buffer.concat(buffers)
+  }
+  const arrow = await collectArrow(df)
+  t.deepEqual(await ctx.readArrowIpc(arrow).collect(), [{ a: 1, b: 2, c: 3 }])
+  // Only the schema and the end-of-stream marker are written for an empty result
+  const empty = await collectArrow(df.filter(col('a').gt(lit(1))))
+  t.deepEqual([...empty.subarray(-8)], [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0])
+  t.deepEqual(await ctx.readArrowIpc(empty).select([col('a'), col('c')]).collect(), [])
+})
+
+test('write files', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const dir = mkdtempSync(join(tmpdir(), 'datafusion-'))
+  await df.writeCsv(join(dir, 'example.csv'), { delimiter: ';' })
+  t.is(readFileSync(join(dir, 'example.csv'), 'utf8'), 'a;b;c\n1;2;3\n')
+  await df.writeJson(join(dir, 'example.json'))
+  t.is(readFileSync(join(dir, 'example.json'), 'utf8'), '{"a":1,"b":2,"c":3}\n')
+  await df.writeParquet(join(dir, 'example.parquet'), { compression: ParquetCompression.Zstd })
+  t.is(readFileSync(join(dir, 'example.parquet')).subarray(0, 4).toString(), 'PAR1')
+})
+
+test('read parquet', async (t) => {
+  const ctx = new SessionContext()
+  const dir = mkdtempSync(join(tmpdir(), 'datafusion-'))
+  const path = join(dir, 'example.parquet')
+  await (await ctx.readCsv(fixture)).writeParquet(path)
+  t.deepEqual(await (await ctx.readParquet(path)).collect(), [{ a: 1, b: 2, c: 3 }])
+  await ctx.registerParquet('example', dir)
+  t.deepEqual(await (await ctx.sql('SELECT a FROM example')).collect(), [{ a: 1 }])
+})
+
+test('read json', async (t) => {
+  const ctx = new SessionContext()
+  const dir = mkdtempSync(join(tmpdir(), 'datafusion-'))
+  const path = join(dir, 'example.json.gz')
+  writeFileSync(path, gzipSync('{"a":1,"b":"x"}\n{"a":2,"b":"y"}\n'))
+  const options = { fileExtension: '.json.gz', fileCompressionType: FileCompressionType.Gzip }
+  t.deepEqual(await (await ctx.readJson(path, options)).collect(), [
+    { a: 1, b: 'x' },
+    { a: 2, b: 'y' },
+  ])
+  await ctx.registerJson('example', dir, {
+    ...options,
+    schema: [{ name: 'a', dataType: { typeId: TypeId.Float64 } }],
+  })
+  t.deepEqual(await (await ctx.sql('SELECT a FROM example')).collect(), [{ a: 1 }, { a: 2 }])
+})
+
+test('read csv with options', async (t) => {
+  const ctx = new SessionContext()
+  const dir = mkdtempSync(join(tmpdir(), 'datafusion-'))
+  const path = join(dir, 'users.csv')
+  writeFileSync(path, '1;\'Alice; A\'\n2;Bob\n')
+  const df = await ctx.readCsv(path, {
+    hasHeader: false,
+    delimiter: ';',
+    quote: "'",
+    schema: [
+      { name: 'id', dataType: { typeId: TypeId.Int32 } },
+      { name: 'name', dataType: { typeId: TypeId.Utf8 } },
+    ],
+  })
+  t.deepEqual(await df.collect(), [
+    { id: 1, name: 'Alice; A' },
+    { id: 2, name: 'Bob' },
+  ])
+})
+
+test('register tables', async (t) => {
+  const ctx = new SessionContext()
+  await ctx.registerCsv('example', fixture)
+  t.deepEqual(await (await ctx.sql('SELECT a FROM example')).collect(), [{ a: 1 }])
+  const df = await ctx.sql('SELECT b, c FROM example')
+  await ctx.registerTable('materialized', df)
+  ctx.registerView('view', df)
+  t.deepEqual(await (await ctx.sql('SELECT b FROM materialized')).collect(), [{ b: 2 }])
+  t.deepEqual(await (await ctx.sql('SELECT c FROM view')).collect(), [{ c: 3 }])
+})
+
+test('join', async (t) => {
+  const ctx = new SessionContext()
+  const left = await ctx.readCsv(fixture)
+  const right = (await ctx.readCsv(fixture)).select([col('a').alias('a2'), col('b').alias('b2'), col('c').alias('c2')])
+  t.deepEqual(await left.join(right, JoinType.Inner, ['a'], ['a2']).select([col('b2')]).collect(), [{ b2: 2 }])
+  const joined = left.joinOn(right, JoinType.Left, [
+    binaryExpr(col('a'), Operator.Eq, col('a2')),
+    binaryExpr(col('b'), Operator.Gt, col('c2')),
+  ])
+  t.deepEqual(await joined.select([col('a'), col('c2')]).collect(), [{ a: 1, c2: null }])
+})
+
+test('transformations return a new DataFrame', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const filtered = df.filter(binaryExpr(col('a'), Operator.Gt, col('b')))
+  const limited = df.limit(0, 1)
+  t.deepEqual(await filtered.collect(), [])
+  t.deepEqual(await limited.collect(), [{ a: 1, b: 2, c: 3 }])
+  t.deepEqual(await df.collect(), [{ a: 1, b: 2, c: 3 }])
+})
+
+test('typed literals', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  t.deepEqual(await df.filter(col('a').ltEq(lit(1))).collect(), [{ a: 1, b: 2, c: 3 }])
+  t.deepEqual(await df.filter(col('b').ltEq(litInt32(1))).collect(), [])
+  const [row] = await df
+    .select([lit(true).alias('bool'), lit(1.5).alias('float'), lit(10n).alias('big'), lit(null).alias('nil')])
+    .collect({ int64AsBigInt: true })
+  t.deepEqual(row, { bool: true, float: 1.5, big: 10n, nil: null })
+  const [{ price }] = await df.select([litDecimal128('12.99', 10, 2).alias('price')]).collect()
+  t.is(price, '12.99')
+  t.is(litTyped(1, { typeId: TypeId.Int8 }).toString(), 'Int8(1)')
+  t.is(litTyped(2n ** 63n, { typeId: TypeId.UInt64 }).toString(), 'UInt64(9223372036854775808)')
+  t.like(litTyped('1.5', { typeId: TypeId.Float32 }).getType(df), { dataType: { typeId: TypeId.Float32 } })
+  t.like(litTyped(null, { typeId: TypeId.Utf8 }).getType(df), { dataType: { typeId: TypeId.Utf8 } })
+  t.throws(() => litTyped(300, { typeId: TypeId.Int8 }))
+  t.like(lit(2 ** 63).getType(df), { dataType: { typeId: TypeId.Float64 } })
+  t.throws(() => lit({}))
+})
+
+test('expression operators', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const [row] = await df
+    .select([
+      col('a').add(col('b')).mul(col('c')).alias('arithmetic'),
+      col('c').sub(col('a')).div(lit(2)).neg().alias('negated'),
+      col('b').between(col('a'), col('c')).alias('between'),
+      col('b').notBetween(col('a'), col('c')).alias('not_between'),
+      col('a').eq(lit(1)).and(col('b').gtEq(lit(2))).alias('eq'),
+      lit(null).isDistinctFrom(col('a')).alias('distinct'),
+    ])
+    .collect()
+  t.deepEqual(row, { arithmetic: 9, negated: -1, between: true, not_between: false, eq: true, distinct: true })
+  const a = col('a')
+  a.add(lit(1))
+  t.is(a.toString(), 'a')
+  const small = a.ltEq(lit(1))
+  const both = small.and(a.gt(lit(0)))
+  a.alias('x')
+  small.alias('y')
+  t.is(a.toString(), 'a')
+  t.is(small.toString(), 'a <= Int64(1)')
+  t.is(both.toString(), 'a <= Int64(1) AND a > Int64(0)')
+})
+
+test('cast', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const [row] = await df
+    .select([
+      col('a').cast({ typeId: TypeId.Utf8 }).alias('utf8'),
+      col('b').cast({ typeId: TypeId.Float64 }).div(lit(4)).alias('float'),
+      lit('x').tryCast({ typeId: TypeId.Int32 }).alias('invalid'),
+    ])
+    .collect()
+  t.deepEqual(row, { utf8: '1', float: 0.5, invalid: null })
+  t.throws(() => col('a').cast({ typeId: TypeId.List }))
+})
+
+test('case expressions', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const [row] = await df
+    .select([
+      when(col('a').gt(lit(1)), lit('big')).when(col('a').eq(lit(1)), lit('one')).otherwise(lit('small')).alias('when'),
+      caseExpr(col('b')).when(lit(1), lit('one')).end().alias('case'),
+    ])
+    .collect()
+  t.deepEqual(row, { when: 'one', case: null })
+})
+
+test('window functions', async (t) => {
+  const ctx = new SessionContext()
+  ctx.registerObjects('scores', [
+    { team: 'a', score: 1 },
+    { team: 'a', score: 3 },
+    { team: 'b', score: 2 },
+  ])
+  const df = await ctx.sql('SELECT * FROM scores')
+  const rows = await df
+    .window([
+      rowNumber().over({ orderBy: [col('score')] }).alias('row_number'),
+      rank()
+        .over({ partitionBy: [col('team')], orderBy: [col('score').sort(false, false)] })
+        .alias('rank'),
+      lag(col('score')).over({ orderBy: [col('score')] }).alias('previous'),
+      sum(col('score'))
+        .over({ orderBy: [col('score')], frame: { units: WindowFrameUnits.Rows, start: -Infinity, end: 0 } })
+        .alias('running_total'),
+    ])
+    .sort([col('score').sort(true, false)])
+    .collect()
+  t.deepEqual(rows, [
+    { team: 'a', score: 1, row_number: 1, rank: 2, previous: null, running_total: 1 },
+    { team: 'b', score: 2, row_number: 2, rank: 1, previous: 1, running_total: 3 },
+    { team: 'a', score: 3, row_number: 3, rank: 1, previous: 2, running_total: 6 },
+  ])
+  t.throws(() => col('score').over())
+})
+
+test('scalar functions', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const [row] = await df
+    .select([
+      abs(col('a').sub(col('c'))).alias('abs'),
+      power(col('b'), col('c')).alias('power'),
+      round(lit(1.25), lit(1)).alias('round'),
+      upper(lit('abc')).alias('upper'),
+      lpad(lit('7'), lit(3), lit('0')).alias('lpad'),
+      splitPart(lit('a,b,c'), lit(','), lit(2)).alias('split_part'),
+      regexpReplace(lit('a1b2'), lit('\\d'), lit('_'), lit('g')).alias('regexp_replace'),
+    ])
+    .collect()
+  t.deepEqual(row, {
+    abs: 2,
+    power: 8,
+    round: 1.3,
+    upper: 'ABC',
+    lpad: '007',
+    split_part: 'b',
+    regexp_replace: 'a_b_',
+  })
+})
+
+test('date and time functions', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const time = lit(new Date('2023-01-02T03:45:30Z'))
+  const [row] = await df
+    .select([
+      datePart('hour', dateTrunc('hour', time)).alias('hour'),
+      datePart('minute', dateBin(interval('15 minutes'), time)).alias('minute'),
+      datePart('day', time.add(interval('1 day'))).alias('day'),
+      datePart('epoch', fromUnixtime(lit(60))).alias('epoch'),
+    ])
+    .collect()
+  t.deepEqual(row, { hour: 3, minute: 45, day: 3, epoch: 60 })
+})
+
+test('nested type functions', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const array = arrayAppend(makeArray([col('a'), col('b')]), col('c'))
+  const list = { typeId: TypeId.List, fields: [{ name: 'item', dataType: { typeId: TypeId.Int64 } }] }
+  const [row] = await df
+    .select([
+      arrayLength(array).alias('length'),
+      arrayElement(array, 3).alias('element'),
+      arrayContains(array, lit(2)).alias('contains'),
+      arrayContains(array, lit(9)).alias('missing'),
+      arrayContains(lit(null).cast(list), lit(2)).alias('null_list'),
+    ])
+    .collect()
+  t.deepEqual(row, { length: 3, element: 3, contains: true, missing: false, null_list: null })
+  const unnested = await df.select([col('a'), array.alias('value')]).unnest('value').collect()
+  t.deepEqual(unnested, [
+    { a: 1, value: 1 },
+    { a: 1, value: 2 },
+    { a: 1, value: 3 },
+  ])
+})
+
+test('subquery expressions', async (t) => {
+  const ctx = new SessionContext()
+  await ctx.registerCsv('customers', fixture)
+  await ctx.registerCsv('orders', fixture)
+  const customers = await ctx.sql('SELECT a, b FROM customers')
+  const orders = await ctx.sql('SELECT a, c FROM orders')
+  t.deepEqual(await customers.filter(col('a').inSubquery(orders.select([col('a')]), false)).collect(), [{ a: 1, b: 2 }])
+  t.deepEqual(
+    await customers
+      .filter(notExists(orders.filter(col('orders.c').eq(outRefCol('customers.b', { typeId: TypeId.Int64 })))))
+      .collect(),
+    [{ a: 1, b: 2 }],
+  )
+  t.deepEqual(await customers.filter(col('b').lt(scalarSubquery(orders.select([col('c')])))).collect(), [
+    { a: 1, b: 2 },
+  ])
+})
+
+test('parse sql expressions', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  t.deepEqual(await df.filter(Expr.parse('a + b * 2 > 4 AND c IN (3, 4)', df)).collect(), [{ a: 1, b: 2, c: 3 }])
+  const total = Expr.parseWithSchema('a + b', [
+    { name: 'a', dataType: { typeId: TypeId.Int64 } },
+    { name: 'b', dataType: { typeId: TypeId.Int64 } },
+  ])
+  t.deepEqual(await df.select([total.alias('sum')]).collect(), [{ sum: 3 }])
+  t.throws(() => Expr.parse('a +', df))
+  t.throws(() => Expr.parse('a > 1 b = 2', df))
+  t.throws(() => Expr.parse('a > 1) OR (1 = 1', df))
+  t.throws(() => Expr.parse('a > 1; junk', df))
+})
+
+test('expr introspection', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const expr = col('a').add(col('b')).gt(lit(1))
+  t.is(expr.toString(), 'a + b > Int64(1)')
+  t.like(expr.toJSON(), {
+    kind: 'BinaryExpr',
+    operator: '>',
+    children: [
+      { kind: 'BinaryExpr', operator: '+' },
+      { kind: 'Literal', value: '1', dataType: { typeId: TypeId.Int64 } },
+    ],
+  })
+  t.like(col('b').notBetween(col('a'), lit(3)).toJSON(), {
+    kind: 'Between',
+    negated: true,
+    children: [{ kind: 'Column', name: 'b' }, { kind: 'Column', name: 'a' }, { kind: 'Literal' }],
+  })
+  t.like(col('a').inList([lit(1), lit(2)], true).toJSON(), { kind: 'InList', negated: true })
+  t.like(col('a').sort(false, true).toJSON(), { kind: 'Sort', asc: false, nullsFirst: true })
+  t.deepEqual(expr.columnRefs(), ['a', 'b'])
+  t.like(col('a').add(lit(1.5)).getType(df), { dataType: { typeId: TypeId.Float64 }, nullable: true })
+  t.like(col('a').gt(lit(1)).getTypeWithSchema([{ name: 'a', dataType: { typeId: TypeId.Int32 }, nullable: false }]), {
+    dataType: { typeId: TypeId.Boolean },
+    nullable: false,
+  })
+  t.like(currentTime().getType(df), { dataType: { typeId: TypeId.Time64, unit: TimeUnit.Nanosecond } })
+  t.like(interval('1 day').getType(df), { dataType: { typeId: TypeId.Interval } })
+  const vector = {
+    typeId: TypeId.FixedSizeList,
+    size: 2,
+    fields: [{ name: 'item', dataType: { typeId: TypeId.Float16 } }],
+  }
+  t.like(col('v').getTypeWithSchema([{ name: 'v', dataType: vector }]), {
+    dataType: { typeId: TypeId.FixedSizeList, size: 2, fields: [{ dataType: { typeId: TypeId.Float16 } }] },
+  })
+})
+
+test('struct field access', async (t) => {
+  const ctx = new SessionContext()
+  const df = await ctx.readCsv(fixture)
+  const nested = struct([col('a'), struct([col('b'), col('c')])])
+  const [row] = await df
+    .select([nested.field('c0').alias('a'), nested.field('c1').field('c1').alias('c')])
+    .collect()
+  t.deepEqual(row, { a: 1, c: 3 })
+})
@@ -0,0 +1,1503 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+/** Join type */
+export const enum JoinType {
+  /** Inner Join */
+  Inner = 0,
+  /** Left Join */
+  Left = 1,
+  /** Right Join */
+  Right = 2,
+  /** Full Join */
+  Full = 3,
+  /** Left Semi Join */
+  LeftSemi = 4,
+  /** Right Semi Join */
+  RightSemi = 5,
+  /** Left Anti Join */
+  LeftAnti = 6,
+  /** Right Anti Join */
+  RightAnti = 7
+}
+/**
+ * Create a column expression based on a qualified or unqualified column name
+ *
+ * example:
+ * ```
+ * const c = col('my_column');
+ * ```
+ */
+export function col(name: string): Expr
+/**
+ * Create a reference to the column `name` of the outer query, to correlate a subquery with it.
+ * `dataType` is the type of the column.
+ *
+ * example:
+ * ```
+ * const orders = (await ctx.sql('SELECT * FROM orders')).filter(
+ *   col('orders.customer_id').eq(outRefCol('customers.id', { typeId: TypeId.Int64 })),
+ * );
+ * const customers = (await ctx.sql('SELECT * FROM customers')).filter(exists(orders));
+ * ```
+ */
+export function outRefCol(name: string, dataType: DataType): Expr
+/**
+ * Create a literal expression, the type of the literal is decided by the JavaScript value:
+ *
+ * - `null` and `undefined` => `Null`
+ * - `boolean` => `Boolean`
+ * - integral `number` => `Int64`, other `number` => `Float64`
+ * - `BigInt` => `Int64`, or `UInt64` if it exceeds `Int64`
+ * - `string` => `Utf8`
+ * - `Date` => `Timestamp` in milliseconds without timezone
+ * - `Buffer` => `Binary`
+ *
+ * Use `litTyped` or the typed constructors like `litInt32` for the other types.
+ *
+ * ```
+ * const expr = col('a').ltEq(lit(5));
+ * ```
+ */
+export function lit(value: string | number | bigint | boolean | Date | Buffer | null): Expr
+/** Create an `Int32` literal expression */
+export function litInt32(value: number): Expr
+/** Create a `Float64` literal expression */
+export function litFloat64(value: number): Expr
+/**
+ * Create a `Decimal128` literal expression from its string representation,
+ * the value must fit in `precision` digits with `scale` digits after the decimal point.
+ *
+ * ```
+ * const price = litDecimal128('12.99', 10, 2);
+ * ```
+ */
+export function litDecimal128(value: string, precision: number, scale: number): Expr
+/**
+ * Create a `Timestamp` literal expression, `value` is the elapsed time since UNIX epoch in `unit`,
+ * which defaults to `Millisecond`.
+ *
+ * ```
+ * const since = litTimestamp(Date.parse('2023-01-01'), TimeUnit.Millisecond, '+00:00');
+ * ```
+ */
+export function litTimestamp(
+  value: number,
+  unit?: TimeUnit | undefined | null,
+  timezone?: string | undefined | null,
+): Expr
+/** Create a `Date32` literal expression, `days` is the elapsed days since UNIX epoch */
+export function litDate32(days: number): Expr
+/**
+ * Create an `Interval` literal expression of `months`, `days` and `nanoseconds`,
+ * which are kept separately because their lengths vary.
+ *
+ * ```
+ * const expr = binaryExpr(col('created_at'), Operator.Plus, litInterval(1, 15, 0)); // 1 month and 15 days later
+ * ```
+ */
+export function litInterval(months: number, days: number, nanoseconds: number): Expr
+/**
+ * Create an `Interval` literal expression from the SQL interval syntax, which can be added to
+ * or subtracted from timestamps and dates.
+ *
+ * ```
+ * const expr = col('created_at').add(interval('1 day 2 hours'));
+ * ```
+ */
+export function interval(value: string): Expr
+/**
+ * Create a literal expression of `dataType`, `value` is converted like [`lit`] and then cast
+ * to `dataType`, which fails if the value does not fit in it.
+ *
+ * ```
+ * const small = litTyped(1, { typeId: TypeId.Int8 });
+ * const unsigned = litTyped(2n ** 63n, { typeId: TypeId.UInt64 });
+ * const day = litTyped(new Date('2023-01-01'), { typeId: TypeId.Date64 });
+ * ```
+ */
+export function litTyped(value: string | number | bigint | boolean | Date | Buffer | null, dataType: DataType): Expr
+/** Return a new expression `left <op> right` */
+export function binaryExpr(left: Expr, op: Operator, right: Expr): Expr
+/** Return a new expression `left <op> right` */
+export function and(left: Expr, right: Expr): Expr
+/** Return a new expression with a logical OR */
+export function or(left: Expr, right: Expr): Expr
+/** Create an expression to represent the min() aggregate function */
+export function min(expr: Expr): Expr
+/** Create an expression to represent the max() aggregate function */
+export function max(expr: Expr): Expr
+/** Create an expression to represent the sum() aggregate function */
+export function sum(expr: Expr): Expr
+/** Create an expression to represent the avg() aggregate function */
+export function avg(expr: Expr): Expr
+/** Create an expression to represent the count() aggregate function */
+export function count(expr: Expr): Expr
+/** Create an expression to represent the count(distinct) aggregate function */
+export function countDistinct(expr: Expr): Expr
+/** Create an in_list expression */
+export function inList(expr: Expr, list: Array<Expr>, negated: boolean): Expr
+/** Create an `EXISTS (<subquery>)` expression */
+export function exists(subquery: DataFrame): Expr
+/** Create a `NOT EXISTS (<subquery>)` expression */
+export function notExists(subquery: DataFrame): Expr
+/** Create a scalar subquery expression, `subquery` must return a single column and at most one row */
+export function scalarSubquery(subquery: DataFrame): Expr
+/** Concatenates the text representations of all the arguments. NULL arguments are ignored. */
+export function concat(args: Array<Expr>): Expr
+/**
+ * Concatenates all but the first argument, with separators.
+ * The first argument is used as the separator.
+ * NULL arguments in `values` are ignored.
+ */
+export function concatWs(sep: Expr, values: Array<Expr>): Expr
+/** Returns a random value in the range 0.0 <= x < 1.0 */
+export function random(): Expr
+/** Absolute value */
+export function abs(expr: Expr): Expr
+/** Nearest integer greater than or equal to `expr` */
+export function ceil(expr: Expr): Expr
+/** Nearest integer less than or equal to `expr` */
+export function floor(expr: Expr): Expr
+/** Round `expr` to `decimalPlaces` digits after the decimal point, which defaults to `0` */
+export function round(expr: Expr, decimalPlaces?: Expr | undefined | null): Expr
+/** Truncate `expr` toward zero */
+export function trunc(expr: Expr): Expr
+/** Sign of `expr`, `-1`, `0` or `1` */
+export function signum(expr: Expr): Expr
+/** Square root */
+export function sqrt(expr: Expr): Expr
+/** Exponential, `e ^ expr` */
+export function exp(expr: Expr): Expr
+/** Natural logarithm */
+export function ln(expr: Expr): Expr
+/** Base 2 logarithm */
+export function log2(expr: Expr): Expr
+/** Base 10 logarithm */
+export function log10(expr: Expr): Expr
+/** Return `base ^ exponent` */
+export function power(base: Expr, exponent: Expr): Expr
+/** Sine, `expr` in radians */
+export function sin(expr: Expr): Expr
+/** Cosine, `expr` in radians */
+export function cos(expr: Expr): Expr
+/** Tangent, `expr` in radians */
+export function tan(expr: Expr): Expr
+/** Inverse sine in radians */
+export function asin(expr: Expr): Expr
+/** Inverse cosine in radians */
+export function acos(expr: Expr): Expr
+/** Inverse tangent in radians */
+export function atan(expr: Expr): Expr
+/** Inverse tangent of `y / x` in radians, using the signs of both to determine the quadrant */
+export function atan2(y: Expr, x: Expr): Expr
+/** Convert the string to lower case */
+export function lower(expr: Expr): Expr
+/** Convert the string to upper case */
+export function upper(expr: Expr): Expr
+/** Remove the spaces from both sides of the string */
+export function trim(expr: Expr): Expr
+/** Remove the spaces from the left side of the string */
+export function ltrim(expr: Expr): Expr
+/** Remove the spaces from the right side of the string */
+export function rtrim(expr: Expr): Expr
+/**
+ * Extract `length` characters of the string from the `start` position, counting from 1,
+ * or the rest of the string if `length` is not provided
+ */
+export function substr(expr: Expr, start: Expr, length?: Expr | undefined | null): Expr
+/** Replace all the occurrences of `from` in the string with `to` */
+export function replace(expr: Expr, from: Expr, to: Expr): Expr
+/** Split the string on `delimiter` and return the `n`th part, counting from 1 */
+export function splitPart(expr: Expr, delimiter: Expr, n: Expr): Expr
+/** Whether the string starts with `prefix` */
+export function startsWith(expr: Expr, prefix: Expr): Expr
+/** Number of characters in the string */
+export function length(expr: Expr): Expr
+/**
+ * Pad the left side of the string with `fill`, which defaults to a space, to `length` characters.
+ * The string is truncated if it's longer than `length`.
+ */
+export function lpad(expr: Expr, length: Expr, fill?: Expr | undefined | null): Expr
+/**
+ * Pad the right side of the string with `fill`, which defaults to a space, to `length` characters.
+ * The string is truncated if it's longer than `length`.
+ */
+export function rpad(expr: Expr, length: Expr, fill?: Expr | undefined | null): Expr
+/** MD5 digest of the string, in hexadecimal */
+export function md5(expr: Expr): Expr
+/** SHA-256 digest of the string or binary */
+export function sha256(expr: Expr): Expr
+/**
+ * Match the string against the regular expression `pattern`, returning the `List` of the captured groups
+ * of the first match, or `NULL` if there is no match. `flags` like `'i'` modify the matching.
+ *
+ * ```
+ * const year = regexpMatch(col('date'), lit('(\\d{4})-\\d{2}-\\d{2}'));
+ * ```
+ */
+export function regexpMatch(expr: Expr, pattern: Expr, flags?: Expr | undefined | null): Expr
+/**
+ * Replace the substrings of the string matching the regular expression `pattern` with `replacement`,
+ * which can refer to the captured groups like `$1`. Only the first match is replaced unless `flags` contains `'g'`.
+ *
+ * ```
+ * const masked = regexpReplace(col('phone'), lit('\\d'), lit('*'), lit('g'));
+ * ```
+ */
+export function regexpReplace(expr: Expr, pattern: Expr, replacement: Expr, flags?: Expr | undefined | null): Expr
+/** Current timestamp in UTC, which is the same for all the rows of a query */
+export function now(): Expr
+/** Current date in UTC, which is the same for all the rows of a query */
+export function currentDate(): Expr
+/** Current time in UTC, which is the same for all the rows of a query */
+export function currentTime(): Expr
+/**
+ * Truncate the timestamp to `granularity`, one of `'year'`, `'quarter'`, `'month'`, `'week'`, `'day'`,
+ * `'hour'`, `'minute'`, `'second'`, `'millisecond'` and `'microsecond'`
+ *
+ * ```
+ * const hour = dateTrunc('hour', col('created_at'));
+ * ```
+ */
+export function dateTrunc(granularity: string, expr: Expr): Expr
+/**
+ * Extract `part` of the timestamp or date as a `Float64`, like `'year'`, `'month'`, `'day'`, `'dow'`,
+ * `'hour'` or `'epoch'`
+ */
+export function datePart(part: string, expr: Expr): Expr
+/**
+ * Bin the timestamp into intervals of `stride` starting from `origin`, which defaults to UNIX epoch.
+ * Unlike `dateTrunc`, `stride` can be any interval.
+ *
+ * ```
+ * const bucket = dateBin(interval('15 minutes'), col('created_at'));
+ * ```
+ */
+export function dateBin(stride: Expr, expr: Expr, origin?: Expr | undefined | null): Expr
+/** Convert the string or the integer of nanoseconds since UNIX epoch into a `Timestamp` in nanoseconds */
+export function toTimestamp(expr: Expr): Expr
+/** Convert the string or the integer of milliseconds since UNIX epoch into a `Timestamp` in milliseconds */
+export function toTimestampMillis(expr: Expr): Expr
+/** Convert the string or the integer of microseconds since UNIX epoch into a `Timestamp` in microseconds */
+export function toTimestampMicros(expr: Expr): Expr
+/** Convert the string or the integer of seconds since UNIX epoch into a `Timestamp` in seconds */
+export function toTimestampSeconds(expr: Expr): Expr
+/** Convert the integer of seconds since UNIX epoch into a `Timestamp` in seconds */
+export function fromUnixtime(expr: Expr): Expr
+/** Create a `List` of the values of `args`, which are coerced into a common type */
+export function makeArray(args: Array<Expr>): Expr
+/** Append `element` to the end of the list */
+export function arrayAppend(array: Expr, element: Expr): Expr
+/** Concatenate the lists */
+export function arrayConcat(arrays: Array<Expr>): Expr
+/** Number of elements in the list */
+export function arrayLength(array: Expr): Expr
+/** Whether the list contains `element`, `NULL` if the list is `NULL` */
+export function arrayContains(array: Expr, element: Expr): Expr
+/**
+ * Element of the list at `index`, counting from 1, or `NULL` if `index` is out of bounds
+ *
+ * ```
+ * const first = arrayElement(col('tags'), 1);
+ * ```
+ */
+export function arrayElement(array: Expr, index: number): Expr
+/** Create a `Struct` of the values of `args`, with the fields named `c0`, `c1`, ... */
+export function struct(args: Array<Expr>): Expr
+/**
+ * Returns the approximate number of distinct input values.
+ * This function provides an approximation of count(DISTINCT x).
+ * Zero is returned if all input values are null.
+ * This function should produce a standard error of 0.81%,
+ * which is the standard deviation of the (approximately normal)
+ * error distribution over all possible sets.
+ * It does not guarantee an upper bound on the error for any specific input set.
+ */
+export function approxDistinct(expr: Expr): Expr
+/** Calculate an approximation of the median for `expr`. */
+export function approxMedian(expr: Expr): Expr
+/** Calculate an approximation of the specified `percentile` for `expr`. */
+export function approxPercentileCont(expr: Expr, percentile: Expr): Expr
+/** Calculate an approximation of the specified `percentile` for `expr` and `weight_expr`. */
+export function approxPercentileContWithWeight(expr: Expr, weightExpr: Expr, percentile: Expr): Expr
+/** Create a grouping set */
+export function groupingSet(exprs: Array<Array<Expr>>): Expr
+/** Create a grouping set for all combination of `exprs` */
+export function cube(exprs: Array<Expr>): Expr
+/** Create a grouping set for rollup */
+export function rollup(exprs: Array<Expr>): Expr
+/** Create is null expression */
+export function isNull(expr: Expr): Expr
+/** Create is true expression */
+export function isTrue(expr: Expr): Expr
+/** Create is not true expression */
+export function isNotTrue(expr: Expr): Expr
+/** Create is false expression */
+export function isFalse(expr: Expr): Expr
+/** Create is not false expression */
+export function isNotFalse(expr: Expr): Expr
+/** Create is unknown expression */
+export function isUnknown(expr: Expr): Expr
+/** Create is not unknown expression */
+export function isNotUnknown(expr: Expr): Expr
+/**
+ * Create a `CASE WHEN when THEN then ...` expression builder with the conditions of the branches
+ *
+ * ```
+ * const bucket = when(col('age').lt(lit(18)), lit('minor'))
+ *   .when(col('age').lt(lit(65)), lit('adult'))
+ *   .otherwise(lit('senior'));
+ * ```
+ */
+export function when(when: Expr, then: Expr): CaseBuilder
+/**
+ * Create a `CASE base WHEN value THEN then ...` expression builder comparing `base` with the values of the branches
+ *
+ * ```
+ * const label = caseExpr(col('status'))
+ *   .when(lit(1), lit('active'))
+ *   .when(lit(2), lit('suspended'))
+ *   .end();
+ * ```
+ */
+export function caseExpr(base: Expr): CaseBuilder
+/** Units of the bounds of a `WindowFrame` */
+export const enum WindowFrameUnits {
+  /** Offsets are numbers of rows */
+  Rows = 'rows',
+  /** Offsets are differences of the value of the single `orderBy` expression */
+  Range = 'range',
+  /** Offsets are numbers of groups of peer rows, which have the same `orderBy` values */
+  Groups = 'groups'
+}
+/**
+ * The frame of a window expression, which is the set of rows in the partition used to compute
+ * the value of the current row.
+ *
+ * Bounds are offsets from the current row, negative for `PRECEDING`, positive for `FOLLOWING`,
+ * `0` for `CURRENT ROW`, `-Infinity` and `Infinity` for `UNBOUNDED PRECEDING` and `UNBOUNDED FOLLOWING`.
+ */
+export interface WindowFrame {
+  units: WindowFrameUnits
+  /** Defaults to `-Infinity` */
+  start?: number
+  /** Defaults to `0` */
+  end?: number
+}
+/** Create a window function returning the number of the current row within its partition, counting from 1 */
+export function rowNumber(): Expr
+/**
+ * Create a window function returning the rank of the current row with gaps,
+ * which is the `rowNumber` of its first peer row
+ */
+export function rank(): Expr
+/**
+ * Create a window function returning the rank of the current row without gaps,
+ * which counts the groups of peer rows
+ */
+export function denseRank(): Expr
+/** Create a window function returning the relative rank of the current row, `(rank - 1) / (rows - 1)` */
+export function percentRank(): Expr
+/**
+ * Create a window function returning the cumulative distribution of the current row,
+ * `(number of rows preceding or peer with the current row) / rows`
+ */
+export function cumeDist(): Expr
+/**
+ * Create a window function dividing the partition into `buckets` groups as equal as possible,
+ * returning the group number of the current row, counting from 1
+ */
+export function ntile(buckets: number): Expr
+/**
+ * Create a window function returning `expr` evaluated at the row `offset` rows before the current row,
+ * or `defaultValue` if there is no such row. `offset` defaults to `1`, `defaultValue` defaults to `NULL`.
+ */
+export function lag(expr: Expr, offset?: number | undefined | null, defaultValue?: Expr | undefined | null): Expr
+/**
+ * Create a window function returning `expr` evaluated at the row `offset` rows after the current row,
+ * or `defaultValue` if there is no such row. `offset` defaults to `1`, `defaultValue` defaults to `NULL`.
+ */
+export function lead(expr: Expr, offset?: number | undefined | null, defaultValue?: Expr | undefined | null): Expr
+/** Create a window function returning `expr` evaluated at the first row of the window frame */
+export function firstValue(expr: Expr): Expr
+/** Create a window function returning `expr` evaluated at the last row of the window frame */
+export function lastValue(expr: Expr): Expr
+/** Create a window function returning `expr` evaluated at the `n`th row of the window frame, counting from 1 */
+export function nthValue(expr: Expr, n: number): Expr
+/**
+ * A node of the tree of an expression, returned by `Expr.toJSON()`
+ *
+ * ```
+ * col('a').gt(lit(1)).toJSON();
+ * // {
+ * //   kind: 'BinaryExpr',
+ * //   operator: '>',
+ * //   children: [
+ * //     { kind: 'Column', name: 'a', children: [] },
+ * //     { kind: 'Literal', value: '1', dataType: { typeId: TypeId.Int64 }, children: [] },
+ * //   ],
+ * // }
+ * ```
+ */
+export interface ExprNode {
+  /** Name of the DataFusion expression variant, like `Column`, `Literal`, `BinaryExpr` or `ScalarFunction` */
+  kind: string
+  /** Name of the column, the alias or the function */
+  name?: string
+  /** Table of the qualified column */
+  relation?: string
+  /** Operator of `BinaryExpr`, like `+`, `=` or `AND` */
+  operator?: string
+  /** Value of `Literal`, formatted as a string, `NULL` for null values */
+  value?: string
+  /** Type of `Literal`, or the target type of `Cast` and `TryCast`, omitted if it is not supported by `DataType` */
+  dataType?: DataType
+  /** Whether `Between`, `InList`, `Like`, `ILike`, `SimilarTo`, `Exists` or `InSubquery` is negated */
+  negated?: boolean
+  /** Escape character of `Like`, `ILike` and `SimilarTo` */
+  escapeChar?: string
+  /** Whether `Sort` is ascending */
+  asc?: boolean
+  /** Whether `Sort` puts nulls first */
+  nullsFirst?: boolean
+  /** Sub-expressions, in the order of the operands or the arguments */
+  children: Array<ExprNode>
+}
+/** Operators applied to expressions */
+export const enum Operator {
+  /** Expressions are equal */
+  Eq = 0,
+  /** Expressions are not equal */
+  NotEq = 1,
+  /** Left side is smaller than right side */
+  Lt = 2,
+  /** Left side is smaller or equal to right side */
+  LtEq = 3,
+  /** Left side is greater than right side */
+  Gt = 4,
+  /** Left side is greater or equal to right side */
+  GtEq = 5,
+  /** Addition */
+  Plus = 6,
+  /** Subtraction */
+  Minus = 7,
+  /** Multiplication operator, like `*` */
+  Multiply = 8,
+  /** Division operator, like `/` */
+  Divide = 9,
+  /** Remainder operator, like `%` */
+  Modulo = 10,
+  /** Logical AND, like `&&` */
+  And = 11,
+  /** Logical OR, like `||` */
+  Or = 12,
+  /** IS DISTINCT FROM */
+  IsDistinctFrom = 13,
+  /** IS NOT DISTINCT FROM */
+  IsNotDistinctFrom = 14,
+  /** Case sensitive regex match */
+  RegexMatch = 15,
+  /** Case insensitive regex match */
+  RegexIMatch = 16,
+  /** Case sensitive regex not match */
+  RegexNotMatch = 17,
+  /** Case insensitive regex not match */
+  RegexNotIMatch = 18,
+  /** Bitwise and, like `&` */
+  BitwiseAnd = 19,
+  /** Bitwise or, like `|` */
+  BitwiseOr = 20,
+  /** Bitwise xor, like `#` */
+  BitwiseXor = 21,
+  /** Bitwise right, like `>>` */
+  BitwiseShiftRight = 22,
+  /** Bitwise left, like `<<` */
+  BitwiseShiftLeft = 23,
+  /** String concat */
+  StringConcat = 24
+}
+/** Compression codec of the files to read */
+export const enum FileCompressionType {
+  /** No compression */
+  Uncompressed = 'uncompressed',
+  /** Gzip compression, usually with the `.gz` extension */
+  Gzip = 'gzip',
+  /** Bzip2 compression, usually with the `.bz2` extension */
+  Bzip2 = 'bzip2',
+  /** Xz compression, usually with the `.xz` extension */
+  Xz = 'xz',
+  /** Zstandard compression, usually with the `.zst` extension */
+  Zstd = 'zstd'
+}
+export interface ParquetReadOptions {
+  /** Only the files with this extension are read, defaults to `.parquet` */
+  fileExtension?: string
+  /**
+   * Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+   * the `nullable` of the fields is ignored
+   */
+  tablePartitionCols?: Array<Field>
+  /**
+   * Skip the row groups and pages with the statistics of the Parquet metadata,
+   * defaults to the `datafusion.execution.parquet.pruning` config
+   */
+  parquetPruning?: boolean
+  /**
+   * Ignore the metadata of the Parquet schema, which avoids conflicts between files with different metadata,
+   * defaults to the `datafusion.execution.parquet.skip_metadata` config
+   */
+  skipMetadata?: boolean
+}
+export interface NdJsonReadOptions {
+  /** The schema of the files, inferred from the first `schemaInferMaxRecords` lines if not provided */
+  schema?: Array<Field>
+  /** Maximum number of lines to read for the schema inference, defaults to `1000` */
+  schemaInferMaxRecords?: number
+  /**
+   * Only the files with this extension are read, defaults to `.json`.
+   * Compressed files usually have another extension, like `.json.gz`
+   */
+  fileExtension?: string
+  /** Defaults to `FileCompressionType.Uncompressed` */
+  fileCompressionType?: FileCompressionType
+  /**
+   * Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+   * the `nullable` of the fields is ignored
+   */
+  tablePartitionCols?: Array<Field>
+}
+export interface CsvReadOptions {
+  /** Whether the first line is a header line with the column names, defaults to `true` */
+  hasHeader?: boolean
+  /** Defaults to `,` */
+  delimiter?: string
+  /** Defaults to `"` */
+  quote?: string
+  /** The escape character in quoted values, quotes are escaped by doubling them if not provided */
+  escape?: string
+  /** The schema of the files, inferred from the first `schemaInferMaxRecords` lines if not provided */
+  schema?: Array<Field>
+  /** Maximum number of lines to read for the schema inference, defaults to `1000` */
+  schemaInferMaxRecords?: number
+  /**
+   * Only the files with this extension are read, defaults to `.csv`.
+   * Compressed files usually have another extension, like `.csv.gz`
+   */
+  fileExtension?: string
+  /** Defaults to `FileCompressionType.Uncompressed` */
+  fileCompressionType?: FileCompressionType
+  /**
+   * Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+   * the `nullable` of the fields is ignored
+   */
+  tablePartitionCols?: Array<Field>
+}
+export interface CollectOptions {
+  /**
+   * Convert `Int64` and `UInt64` values into `BigInt` instead of `number`.
+   *
+   * Defaults to `false`, values beyond `Number.MAX_SAFE_INTEGER` lose precision.
+   */
+  int64AsBigInt?: boolean
+}
+/** Arrow IPC format */
+export const enum ArrowIpcFormat {
+  /** The streaming format, read it with `RecordBatchStreamReader` or `tableFromIPC` */
+  Stream = 'stream',
+  /** The random access file format, read it with `RecordBatchFileReader` or `tableFromIPC` */
+  File = 'file'
+}
+export interface ArrowIpcOptions {
+  /** Defaults to `ArrowIpcFormat.Stream` */
+  format?: ArrowIpcFormat
+}
+/** Format of the chunks of `DataFrame.toReadableStream` */
+export const enum ReadableStreamFormat {
+  /** Newline delimited JSON, one line per row */
+  Ndjson = 'ndjson',
+  /** CSV with a header line */
+  Csv = 'csv',
+  /** Arrow IPC streaming format */
+  Arrow = 'arrow',
+  /** Row objects in object mode, see `DataFrame.collect` for the type mapping */
+  Objects = 'objects'
+}
+export interface ReadableStreamOptions {
+  /** Defaults to `ReadableStreamFormat.Objects` */
+  format?: ReadableStreamFormat
+  /** Convert `Int64` and `UInt64` values into `BigInt` in the `objects` format. */
+  int64AsBigInt?: boolean
+}
+/** The type of a [`DataType`] */
+export const enum TypeId {
+  /** Null type */
+  Null = 'Null',
+  /** A boolean type representing the values `true` and `false` */
+  Boolean = 'Boolean',
+  /** A signed 8-bit integer */
+  Int8 = 'Int8',
+  /** A signed 16-bit integer */
+  Int16 = 'Int16',
+  /** A signed 32-bit integer */
+  Int32 = 'Int32',
+  /** A signed 64-bit integer */
+  Int64 = 'Int64',
+  /** An unsigned 8-bit integer */
+  UInt8 = 'UInt8',
+  /** An unsigned 16-bit integer */
+  UInt16 = 'UInt16',
+  /** An unsigned 32-bit integer */
+  UInt32 = 'UInt32',
+  /** An unsigned 64-bit integer */
+  UInt64 = 'UInt64',
+  /** A 16-bit floating point number */
+  Float16 = 'Float16',
+  /** A 32-bit floating point number */
+  Float32 = 'Float32',
+  /** A 64-bit floating point number */
+  Float64 = 'Float64',
+  /** A variable-length string in Unicode with UTF-8 encoding */
+  Utf8 = 'Utf8',
+  /** A variable-length string in Unicode with UTF-8 encoding and 64-bit offsets */
+  LargeUtf8 = 'LargeUtf8',
+  /** Opaque binary data of variable length */
+  Binary = 'Binary',
+  /** Opaque binary data of variable length and 64-bit offsets */
+  LargeBinary = 'LargeBinary',
+  /** Opaque binary data of `size` bytes per value */
+  FixedSizeBinary = 'FixedSizeBinary',
+  /** Elapsed time since UNIX epoch in days, 32 bits */
+  Date32 = 'Date32',
+  /** Elapsed time since UNIX epoch in milliseconds, 64 bits */
+  Date64 = 'Date64',
+  /** Elapsed time since UNIX epoch in `unit`, with an optional `timezone` */
+  Timestamp = 'Timestamp',
+  /** Elapsed time since midnight in `unit`, either `Second` or `Millisecond`, 32 bits */
+  Time32 = 'Time32',
+  /** Elapsed time since midnight in `unit`, either `Microsecond` or `Nanosecond`, 64 bits */
+  Time64 = 'Time64',
+  /** Measure of elapsed time in `unit`, 64 bits */
+  Duration = 'Duration',
+  /** Calendar interval in `intervalUnit` */
+  Interval = 'Interval',
+  /** Exact 128-bit width decimal value with `precision` and `scale` */
+  Decimal128 = 'Decimal128',
+  /** A list of values of the single field in `fields` */
+  List = 'List',
+  /** A list of values of the single field in `fields` and 64-bit offsets */
+  LargeList = 'LargeList',
+  /** A list of `size` values of the single field in `fields` */
+  FixedSizeList = 'FixedSizeList',
+  /** A nested value with `fields` */
+  Struct = 'Struct',
+  /** A map of the key and value, which are the two fields in `fields` */
+  Map = 'Map',
+  /** Values of the single field in `fields` encoded by the integer `keyType` */
+  Dictionary = 'Dictionary'
+}
+/** Time unit of `Timestamp`, `Time32`, `Time64` and `Duration` */
+export const enum TimeUnit {
+  /** Time in seconds */
+  Second = 'Second',
+  /** Time in milliseconds */
+  Millisecond = 'Millisecond',
+  /** Time in microseconds */
+  Microsecond = 'Microsecond',
+  /** Time in nanoseconds */
+  Nanosecond = 'Nanosecond'
+}
+/** Unit of `Interval` */
+export const enum IntervalUnit {
+  /** Number of months */
+  YearMonth = 'YearMonth',
+  /** Number of days and milliseconds */
+  DayTime = 'DayTime',
+  /** Number of months, days and nanoseconds */
+  MonthDayNano = 'MonthDayNano'
+}
+/**
+ * Arrow data type
+ *
+ * ```
+ * import { TimeUnit, TypeId } from '@napi-rs/datafusion';
+ * const int64 = { typeId: TypeId.Int64 };
+ * const timestamp = { typeId: TypeId.Timestamp, unit: TimeUnit.Millisecond, timezone: '+00:00' };
+ * const decimal = { typeId: TypeId.Decimal128, precision: 10, scale: 2 };
+ * const list = { typeId: TypeId.List, fields: [{ name: 'item', dataType: { typeId: TypeId.Utf8 } }] };
+ * const map = {
+ *   typeId: TypeId.Map,
+ *   fields: [
+ *     { name: 'key', dataType: { typeId: TypeId.Utf8 }, nullable: false },
+ *     { name: 'value', dataType: { typeId: TypeId.Int64 } },
+ *   ],
+ * };
+ * const dictionary = {
+ *   typeId: TypeId.Dictionary,
+ *   keyType: TypeId.Int32,
+ *   fields: [{ name: 'value', dataType: { typeId: TypeId.Utf8 } }],
+ * };
+ * ```
+ */
+export interface DataType {
+  typeId: TypeId
+  /** Precision of `Decimal128`, required by `Decimal128` */
+  precision?: number
+  /** Scale of `Decimal128`, defaults to `0` */
+  scale?: number
+  /**
+   * Unit of `Timestamp`, `Time32`, `Time64` and `Duration`, defaults to `Nanosecond` for `Time64`
+   * and `Millisecond` for the others
+   */
+  unit?: TimeUnit
+  /** Timezone of `Timestamp` */
+  timezone?: string
+  /** Unit of `Interval`, defaults to `MonthDayNano` */
+  intervalUnit?: IntervalUnit
+  /** Number of bytes of `FixedSizeBinary`, or number of values of `FixedSizeList`, required by both */
+  size?: number
+  /** Child fields of `List`, `LargeList`, `FixedSizeList`, `Struct`, `Map` and `Dictionary` */
+  fields?: Array<Field>
+  /** Key type of `Dictionary`, one of the integer types, defaults to `Int32` */
+  keyType?: TypeId
+}
+/** A field of a schema, which is a named column with its data type */
+export interface Field {
+  name: string
+  dataType: DataType
+  /** Defaults to `true` */
+  nullable?: boolean
+}
+export interface RegisterObjectsOptions {
+  /** The schema of the table, inferred from the values if not provided */
+  schema?: Array<Field>
+}
+/** Compression codec of Parquet files */
+export const enum ParquetCompression {
+  /** No compression */
+  Uncompressed = 'uncompressed',
+  /** Snappy compression */
+  Snappy = 'snappy',
+  /** Gzip compression with the default level */
+  Gzip = 'gzip',
+  /** Brotli compression with the default level */
+  Brotli = 'brotli',
+  /** LZ4 compression without the Hadoop framing */
+  Lz4Raw = 'lz4_raw',
+  /** Zstandard compression with the default level */
+  Zstd = 'zstd'
+}
+export interface ParquetWriteOptions {
+  /** Defaults to `ParquetCompression.Uncompressed` */
+  compression?: ParquetCompression
+  /** Maximum number of rows in a row group, defaults to `1048576` */
+  maxRowGroupSize?: number
+  /**
+   * Write all the results into the single file at `path`, or one file per partition
+   * into the directory at `path`. Defaults to `true`
+   */
+  singleFileOutput?: boolean
+}
+export interface CsvWriteOptions {
+  /** Defaults to `,` */
+  delimiter?: string
+  /** Write the column names as the first line, defaults to `true` */
+  hasHeader?: boolean
+  /**
+   * Write all the results into the single file at `path`, or one file per partition
+   * into the directory at `path`. Defaults to `true`
+   */
+  singleFileOutput?: boolean
+}
+export interface JsonWriteOptions {
+  /**
+   * Write all the results into the single file at `path`, or one file per partition
+   * into the directory at `path`. Defaults to `true`
+   */
+  singleFileOutput?: boolean
+}
+export class DataFrame {
+  /** Clone the current `DataFrame` and return a new `DataFrame` instance. */
+  clone(): DataFrame
+  /**
+   * Filter the DataFrame by column. Returns a new DataFrame only containing the
+   * specified columns.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.selectColumns(&["a", "b"])?;
+   * ```
+   */
+  selectColumns(columns: Array<string>): DataFrame
+  /**
+   * Create a projection based on arbitrary expressions.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.select([col("a") * col("b"), col("c")]);
+   * ```
+   */
+  select(exprList: Array<Expr>): DataFrame
+  /** Filter a DataFrame to only include rows that match the specified filter expression. */
+  filter(expr: Expr): DataFrame
+  /** Perform an aggregate query with optional grouping expressions. */
+  aggregate(groupExpr: Array<Expr>, aggrExpr: Array<Expr>): DataFrame
+  /**
+   * Append the results of window expressions as new columns, keeping all the existing columns.
+   *
+   * ```
+   * import { col, rowNumber, SessionContext, sum } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.window([
+   *   rowNumber().over({ orderBy: [col('a')] }).alias('row_number'),
+   *   sum(col('b')).over({ orderBy: [col('a')] }).alias('running_total'),
+   * ]);
+   * ```
+   */
+  window(windowExprs: Array<Expr>): DataFrame
+  /**
+   * Expand the list `column` into one row per element, the values of the other columns are repeated.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.sql('SELECT 1 AS id, make_array(1, 2, 3) AS values');
+   * df.unnest('values'); // 3 rows
+   * ```
+   */
+  unnest(column: string): DataFrame
+  /**
+   * Limit the number of rows returned from this DataFrame.
+   *
+   * `skip` - Number of rows to skip before fetch any row
+   *
+   * `fetch` - Maximum number of rows to fetch, after skipping `skip` rows.
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.limit(0, Some(100));
+   * ```
+   */
+  limit(skip: number, fetch?: number | undefined | null): DataFrame
+  /**
+   * Calculate the union of two [`DataFrame`]s, preserving duplicate rows.The
+   * two [`DataFrame`]s must have exactly the same schema
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * const d2 = df.clone();
+   * df.union(d2);
+   * ```
+   */
+  union(dataframe: DataFrame): DataFrame
+  /**
+   * Calculate the distinct union of two [`DataFrame`]s.  The
+   * two [`DataFrame`]s must have exactly the same schema
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.distinct();
+   * ```
+   */
+  unionDistinct(dataframe: DataFrame): DataFrame
+  /**
+   * Filter out duplicate rows
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.distinct(d2);
+   * ```
+   */
+  distinct(): DataFrame
+  /**
+   * Sort the DataFrame by the specified sorting expressions. Any expression can be turned into
+   * a sort expression by calling its [sort](../logical_plan/enum.Expr.html#method.sort) method.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * df.sort([col('a').sort(true, true), col('b').sort(false, false)]);
+   * ```
+   */
+  sort(expr: Array<Expr>): DataFrame
+  /**
+   * Join this DataFrame with another DataFrame using the specified columns as join keys.
+   *
+   * Filter expression expected to contain non-equality predicates that can not be pushed
+   * down to any of join inputs.
+   * In case of outer join, filter applied to only matched rows.
+   *
+   * ```
+   * import { col, JoinType, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const left = await ctx.readCsv('tests/data/example.csv');
+   * const right = (await ctx.readCsv('tests/data/example.csv'))
+   *   .select([
+   *     col('a').alias('a2'),
+   *     col('b').alias('b2'),
+   *     col('c').alias('c2'),
+   *   ]);
+   * const batches = await left.join(right, JoinType.Inner, ['a', 'b'], ['a2', 'b2']).collect();
+   * ```
+   */
+  join(
+    right: DataFrame,
+    joinType: JoinType,
+    leftCols: Array<string>,
+    rightCols: Array<string>,
+    filter?: Expr | undefined | null,
+  ): DataFrame
+  /**
+   * Join this DataFrame with another DataFrame using arbitrary expressions as join conditions,
+   * all the expressions in `onExprs` must be true for a pair of rows to match.
+   *
+   * Equality predicates between the columns of both sides are executed as hash join keys,
+   * the other predicates, like ranges, are applied as the join filter.
+   *
+   * ```
+   * import { binaryExpr, col, JoinType, Operator, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const left = await ctx.readCsv('tests/data/example.csv');
+   * const right = (await ctx.readCsv('tests/data/example.csv'))
+   *   .select([
+   *     col('a').alias('a2'),
+   *     col('b').alias('b2'),
+   *     col('c').alias('c2'),
+   *   ]);
+   * const batches = await left
+   *   .joinOn(right, JoinType.Left, [
+   *     binaryExpr(col('a'), Operator.Eq, col('a2')),
+   *     binaryExpr(col('b'), Operator.Lt, col('c2')),
+   *   ])
+   *   .collect();
+   * ```
+   */
+  joinOn(right: DataFrame, joinType: JoinType, onExprs: Array<Expr>): DataFrame
+  /**
+   * Print results.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion'
+   *
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * await df.show();
+   * ```
+   */
+  show(): Promise<void>
+  /**
+   * Execute this `DataFrame` and collect the results into an `Array` of row objects
+   * keyed by column name.
+   *
+   * Values are converted from Arrow types into JavaScript types:
+   *
+   * - `null` values => `null`
+   * - `Boolean` => `boolean`
+   * - `Int8` ~ `Int32`, `UInt8` ~ `UInt32`, `Float32`, `Float64` => `number`
+   * - `Int64`, `UInt64` => `number`, or `BigInt` if `int64AsBigInt` is set
+   * - `Utf8`, `LargeUtf8` => `string`
+   * - `Binary`, `LargeBinary` => `Buffer`
+   * - other types are formatted into their `string` representation
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * const rows = await df.collect({ int64AsBigInt: true });
+   * ```
+   */
+  collect(options?: CollectOptions | undefined | null): Promise<Array<Record<string, any>>>
+  /**
+   * Execute this `DataFrame` and collect the results column by column.
+   *
+   * `columns` maps every column name to its values, built from the Arrow buffers:
+   *
+   * - `Int8`, `Int16`, `Int32` => `Int8Array`, `Int16Array`, `Int32Array`
+   * - `UInt8`, `UInt16`, `UInt32` => `Uint8Array`, `Uint16Array`, `Uint32Array`
+   * - `Int64`, `UInt64` => `BigInt64Array`, `BigUint64Array`
+   * - `Float32`, `Float64` => `Float32Array`, `Float64Array`
+   * - `Boolean` => `Uint8Array` of `0` and `1`
+   * - `Date32` => `Int32Array` of days since UNIX epoch
+   * - `Date64` => `BigInt64Array` of milliseconds since UNIX epoch
+   * - `Timestamp` => `BigInt64Array` of the raw values since UNIX epoch in the `unit` of the column,
+   *   the timezone is not applied
+   * - `Utf8`, `LargeUtf8` => `Array<string | null>`
+   * - other types are formatted into `Array<string | null>`
+   *
+   * `validity` maps every column name to its validity bitmap, bit `i % 8` of byte `i / 8`
+   * is set if the value at `i` is not null. It's `null` if the column contains no nulls.
+   * The values of the null slots in the `TypedArray`s are unspecified.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * const { columns, validity } = await df.collectColumns();
+   * ```
+   */
+  collectColumns(): Promise<{
+    columns: Record<
+      string,
+      | Int8Array
+      | Int16Array
+      | Int32Array
+      | BigInt64Array
+      | Uint8Array
+      | Uint16Array
+      | Uint32Array
+      | BigUint64Array
+      | Float32Array
+      | Float64Array
+      | Array<string | null>
+    >
+    validity: Record<string, Uint8Array | null>
+  }>
+  /**
+   * Execute this `DataFrame` and serialize the results with Arrow IPC, the `Buffer` can be
+   * passed to `tableFromIPC` of `apache-arrow` or written to disk as is.
+   *
+   * ```
+   * import { tableFromIPC } from 'apache-arrow';
+   * import { ArrowIpcFormat, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * const table = tableFromIPC(await df.toArrowIpc({ format: ArrowIpcFormat.Stream }));
+   * ```
+   */
+  toArrowIpc(options?: ArrowIpcOptions | undefined | null): Promise<Buffer>
+  /**
+   * Execute this `DataFrame` lazily and iterate over the results one `RecordBatch` at a time,
+   * each yielded as an `Array` of row objects, see `collect` for the type mapping.
+   *
+   * The next batch is only computed when the consumer asks for it, so large results can be
+   * consumed without holding them in memory.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * for await (const rows of df.executeStream()) {
+   *   console.log(rows);
+   * }
+   * ```
+   */
+  executeStream(options?: CollectOptions | undefined | null): AsyncIterableIterator<Array<Record<string, any>>>
+  /**
+   * Execute this `DataFrame` and write the results into Parquet files.
+   *
+   * ```
+   * import { ParquetCompression, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * await df.writeParquet('example.parquet', { compression: ParquetCompression.Zstd, maxRowGroupSize: 65536 });
+   * ```
+   */
+  writeParquet(path: string, options?: ParquetWriteOptions | undefined | null): Promise<void>
+  /**
+   * Execute this `DataFrame` and write the results into CSV files.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * await df.writeCsv('output', { delimiter: ';', singleFileOutput: false });
+   * ```
+   */
+  writeCsv(path: string, options?: CsvWriteOptions | undefined | null): Promise<void>
+  /**
+   * Execute this `DataFrame` and write the results into newline delimited JSON files.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * await df.writeJson('example.json');
+   * ```
+   */
+  writeJson(path: string, options?: JsonWriteOptions | undefined | null): Promise<void>
+}
+export class Expr {
+  /** Create a literal expression, see [`lit`] for the mapping of the value types */
+  static lit(value: string | number | bigint | boolean | Date | Buffer | null): Expr
+  /**
+   * Parse the SQL expression `sql`, with the columns resolved against the schema of `df`
+   *
+   * ```
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * const rule = Expr.parse("a + b * 2 > 10 AND c LIKE 'x%'", df);
+   * df.filter(rule);
+   * ```
+   */
+  static parse(sql: string, df: DataFrame): Expr
+  /** Parse the SQL expression `sql`, with the columns resolved against `schema` */
+  static parseWithSchema(sql: string, schema: Array<Field>): Expr
+  /** Return `self AS name` alias expression */
+  alias(name: string): Expr
+  /** Return `self = other` */
+  eq(other: Expr): Expr
+  /** Return `self != other` */
+  notEq(other: Expr): Expr
+  /** Return `self < other` */
+  lt(other: Expr): Expr
+  /** Return `self <= other` */
+  ltEq(other: Expr): Expr
+  /** Return `self > other` */
+  gt(other: Expr): Expr
+  /** Return `self >= other` */
+  gtEq(other: Expr): Expr
+  /** Return `self + other` */
+  add(other: Expr): Expr
+  /** Return `self - other` */
+  sub(other: Expr): Expr
+  /** Return `self * other` */
+  mul(other: Expr): Expr
+  /** Return `self / other` */
+  div(other: Expr): Expr
+  /** Return `-self` */
+  neg(): Expr
+  /** Return `self BETWEEN low AND high` */
+  between(low: Expr, high: Expr): Expr
+  /** Return `self NOT BETWEEN low AND high` */
+  notBetween(low: Expr, high: Expr): Expr
+  /** Return `self IS DISTINCT FROM other`, which treats `NULL` as a comparable value */
+  isDistinctFrom(other: Expr): Expr
+  /** Return `self IS NOT DISTINCT FROM other`, which treats `NULL` as a comparable value */
+  isNotDistinctFrom(other: Expr): Expr
+  /** Return `self && other` */
+  and(other: Expr): Expr
+  /** Return `self || other` */
+  or(other: Expr): Expr
+  /** Return `!self` */
+  not(): Expr
+  /**
+   * Calculate the modulus of two expressions.
+   * Return `self % other`
+   */
+  modulus(other: Expr): Expr
+  /** Return `self LIKE other` */
+  like(other: Expr): Expr
+  /** Return `self NOT LIKE other` */
+  notLike(other: Expr): Expr
+  /** Return `self ILIKE other` */
+  ilike(other: Expr): Expr
+  /** Return `self NOT ILIKE other` */
+  notIlike(other: Expr): Expr
+  /**
+   * Return `CAST(self AS dataType)`, which fails the execution if a value can not be cast.
+   *
+   * ```
+   * const expr = col('price').cast({ typeId: TypeId.Decimal128, precision: 10, scale: 2 });
+   * ```
+   */
+  cast(dataType: DataType): Expr
+  /** Return `TRY_CAST(self AS dataType)`, which returns `NULL` if a value can not be cast. */
+  tryCast(dataType: DataType): Expr
+  /**
+   * Return the field `name` of the struct
+   *
+   * Looking up a key of a `Map` is not supported by DataFusion 25, planning fails with
+   * an error if `self` is a `Map`.
+   *
+   * ```
+   * const city = col('payload').field('address').field('city');
+   * ```
+   */
+  field(name: string): Expr
+  /** Remove an alias from an expression if one exists. */
+  unalias(): Expr
+  /**
+   * Return `self IN <list>` if `negated` is false, otherwise
+   * return `self NOT IN <list>`.a
+   */
+  inList(list: Array<Expr>, negated: boolean): Expr
+  /**
+   * Return `self IN (<subquery>)` if `negated` is false, otherwise
+   * return `self NOT IN (<subquery>)`, `subquery` must return a single column.
+   */
+  inSubquery(subquery: DataFrame, negated: boolean): Expr
+  /** Return `IsNull(Box(self)) */
+  isNull(): Expr
+  /** Return `IsNotNull(Box(self)) */
+  isNotNull(): Expr
+  /**
+   * Create a sort expression from an existing expression.
+   *
+   * ```
+   * const sortExpr = col('foo').sort(true, true); // SORT ASC NULLS_FIRST
+   * ```
+   */
+  sort(asc: boolean, nullsFirst: boolean): Expr
+  /** Return `IsTrue(Box(self))` */
+  isTrue(): Expr
+  /** Return `IsNotTrue(Box(self))` */
+  isNotTrue(): Expr
+  /** Return `IsFalse(Box(self))` */
+  isFalse(): Expr
+  /** Return `IsNotFalse(Box(self))` */
+  isNotFalse(): Expr
+  /** Return `IsUnknown(Box(self))` */
+  isUnknown(): Expr
+  /** Return `IsNotUnknown(Box(self))` */
+  isNotUnknown(): Expr
+  /**
+   * Turn a window function, or an aggregate function, into a window expression
+   * computed over the partitions and frame described by `options`.
+   *
+   * `orderBy` accepts sort expressions, other expressions are sorted in ascending order with nulls last.
+   *
+   * ```
+   * const runningTotal = sum(col('amount')).over({
+   *   partitionBy: [col('user_id')],
+   *   orderBy: [col('created_at').sort(true, false)],
+   *   frame: { units: WindowFrameUnits.Rows, start: -Infinity, end: 0 },
+   * });
+   * const ranking = rank().over({ partitionBy: [col('group')], orderBy: [col('score').sort(false, false)] });
+   * ```
+   */
+  over(
+    options?: { partitionBy?: Array<Expr>; orderBy?: Array<Expr>; frame?: WindowFrame } | undefined | null,
+  ): Expr
+  /**
+   * Format the expression like DataFusion does in the plans and the column names
+   *
+   * ```
+   * col('a').add(lit(1)).toString(); // 'a + Int64(1)'
+   * ```
+   */
+  toString(): string
+  /** The tree of the expression, `JSON.stringify(expr)` serializes it */
+  toJSON(): ExprNode
+  /** Qualified names of the columns referenced by the expression, in alphabetical order */
+  columnRefs(): Array<string>
+  /**
+   * Resolve the output field of the expression against the schema of `df`,
+   * which is named after the expression like in the results of `df.select([expr])`
+   *
+   * ```
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * col('a').add(lit(1.5)).getType(df);
+   * // { name: 'a + Float64(1.5)', dataType: { typeId: TypeId.Float64 }, nullable: true }
+   * ```
+   */
+  getType(df: DataFrame): Field
+  /** Resolve the output field of the expression against `schema`, see `getType` */
+  getTypeWithSchema(schema: Array<Field>): Field
+  /** Clone the `Expr` and return the new `Expr` instance. */
+  clone(): Expr
+}
+/** Builder of `CASE` expressions, created by `when` or `caseExpr` */
+export class CaseBuilder {
+  /** Add a `WHEN when THEN then` branch */
+  when(when: Expr, then: Expr): this
+  /** Finish the `CASE` expression with `ELSE value` */
+  otherwise(value: Expr): Expr
+  /** Finish the `CASE` expression without `ELSE`, which results in `NULL` if no branch matches */
+  end(): Expr
+}
+/**
+ * An async iterator over the results of a `DataFrame`, yielding one `RecordBatch` at a time.
+ *
+ * Batches are only computed when `next()` is called, so a slow consumer applies backpressure
+ * to the execution instead of having the results buffered in memory.
+ */
+export class RecordBatchStream {
+  /**
+   * Poll the next `RecordBatch` and convert it into an `Array` of row objects,
+   * or a `Buffer` if the stream is created by `DataFrame.toReadableStream` with a byte format.
+   */
+  next(): Promise<IteratorResult<Array<Record<string, any>> | Buffer, undefined>>
+  /**
+   * Stop the execution and release the resources held by the stream,
+   * called by `for await` when the loop exits early.
+   */
+  return(): Promise<IteratorResult<Array<Record<string, any>> | Buffer, undefined>>
+}
+export class SessionContext {
+  constructor()
+  /** Create `SessionContext` from an execution config with config options read from the environment */
+  static withConfigEnv(): SessionContext
+  /**
+   * Creates a [`DataFrame`] that will execute a SQL query.
+   *
+   * Note: This api implements DDL such as `CREATE TABLE` and `CREATE VIEW` with in memory
+   * default implementations.
+   */
+  sql(sql: string): Promise<DataFrame>
+  /**
+   * Creates a [`DataFrame`] for reading a CSV data source, `path` can be a single file
+   * or a directory of files.
+   *
+   * ```
+   * import { SessionContext, TypeId } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('data/users.csv', {
+   *   hasHeader: false,
+   *   delimiter: ';',
+   *   schema: [
+   *     { name: 'id', dataType: { typeId: TypeId.Int64 } },
+   *     { name: 'name', dataType: { typeId: TypeId.Utf8 } },
+   *   ],
+   * });
+   * ```
+   */
+  readCsv(path: string, options?: CsvReadOptions | undefined | null): Promise<DataFrame>
+  /**
+   * Registers a CSV data source as a table that can be referenced from SQL statements
+   * executed against this context.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * await ctx.registerCsv('example', 'tests/data/example.csv');
+   * const df = await ctx.sql('SELECT a, b FROM example');
+   * ```
+   */
+  registerCsv(name: string, path: string, options?: CsvReadOptions | undefined | null): Promise<void>
+  /**
+   * Creates a [`DataFrame`] for reading a Parquet data source, `path` can be a single file
+   * or a directory of files.
+   *
+   * ```
+   * import { SessionContext, TypeId } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readParquet('data/events', {
+   *   tablePartitionCols: [{ name: 'year', dataType: { typeId: TypeId.Int32 } }],
+   * });
+   * ```
+   */
+  readParquet(path: string, options?: ParquetReadOptions | undefined | null): Promise<DataFrame>
+  /**
+   * Registers a Parquet data source as a table that can be referenced from SQL statements
+   * executed against this context.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * await ctx.registerParquet('events', 'data/events', { fileExtension: '.pq' });
+   * const df = await ctx.sql('SELECT count(*) FROM events');
+   * ```
+   */
+  registerParquet(name: string, path: string, options?: ParquetReadOptions | undefined | null): Promise<void>
+  /**
+   * Creates a [`DataFrame`] for reading a newline delimited JSON data source, `path` can be a single file
+   * or a directory of files.
+   *
+   * ```
+   * import { FileCompressionType, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readJson('logs/app.json.gz', {
+   *   fileExtension: '.json.gz',
+   *   fileCompressionType: FileCompressionType.Gzip,
+   * });
+   * ```
+   */
+  readJson(path: string, options?: NdJsonReadOptions | undefined | null): Promise<DataFrame>
+  /**
+   * Registers a newline delimited JSON data source as a table that can be referenced from SQL statements
+   * executed against this context.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * await ctx.registerJson('logs', 'logs', { schemaInferMaxRecords: 100 });
+   * const df = await ctx.sql("SELECT count(*) FROM logs WHERE level = 'error'");
+   * ```
+   */
+  registerJson(name: string, path: string, options?: NdJsonReadOptions | undefined | null): Promise<void>
+  /**
+   * Creates a [`DataFrame`] from an Arrow IPC `Buffer`, in either the streaming format or the file format.
+   *
+   * ```
+   * import { tableToIPC } from 'apache-arrow';
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = ctx.readArrowIpc(Buffer.from(tableToIPC(table, 'stream')));
+   * ```
+   */
+  readArrowIpc(buffer: Buffer): DataFrame
+  /**
+   * Registers an Arrow IPC `Buffer`, in either the streaming format or the file format,
+   * as a table that can be referenced from SQL statements executed against this context.
+   *
+   * ```
+   * import { tableToIPC } from 'apache-arrow';
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * ctx.registerArrowIpc('example', Buffer.from(tableToIPC(table, 'stream')));
+   * const df = await ctx.sql('SELECT * FROM example');
+   * ```
+   */
+  registerArrowIpc(name: string, buffer: Buffer): void
+  /**
+   * Executes `df` and registers the results as an in-memory table that can be referenced
+   * from SQL statements executed against this context.
+   *
+   * Unlike `registerView`, `df` is executed once, and later changes of its sources are not visible.
+   *
+   * ```
+   * import { col, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * await ctx.registerTable('example', df.filter(col('a').ltEq(col('b'))));
+   * const result = await ctx.sql('SELECT count(*) FROM example');
+   * ```
+   */
+  registerTable(name: string, df: DataFrame): Promise<void>
+  /**
+   * Registers `df` as a view that can be referenced from SQL statements executed against
+   * this context, `df` is executed every time the view is queried.
+   *
+   * ```
+   * import { col, SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * const df = await ctx.readCsv('tests/data/example.csv');
+   * ctx.registerView('example', df.filter(col('a').ltEq(col('b'))));
+   * const result = await ctx.sql('SELECT count(*) FROM example');
+   * ```
+   */
+  registerView(name: string, df: DataFrame): void
+  /**
+   * Registers an `Array` of plain objects as a table that can be referenced from SQL statements
+   * executed against this context.
+   *
+   * Without a `schema`, the columns are the keys of the objects, and their types are inferred from the values:
+   *
+   * - only `null` and `undefined` => `Null`
+   * - `boolean` => `Boolean`
+   * - integral `number` and `BigInt` => `Int64`
+   * - `number` and `BigInt` => `Float64`
+   * - `Date` => `Timestamp` in milliseconds without timezone
+   * - otherwise => `Utf8`, objects and arrays are converted into JSON strings, dates into ISO 8601 strings
+   *
+   * `BigInt` values out of the range of `Int64` are rejected.
+   *
+   * With a `schema`, the values are cast into the types of the schema.
+   *
+   * ```
+   * import { SessionContext } from '@napi-rs/datafusion';
+   * const ctx = new SessionContext();
+   * ctx.registerObjects('users', [{ id: 1, name: 'Alice' }, { id: 2, name: 'Bob' }]);
+   * const df = await ctx.sql('SELECT name FROM users WHERE id = 1');
+   * ```
+   */
+  registerObjects(
+    name: string,
+    rows: Array<Record<string, any>>,
+    options?: RegisterObjectsOptions | undefined | null,
+  ): void
+}
@@ -0,0 +1,477 @@
+const { existsSync, readFileSync } = require('fs')
+const { join } = require('path')
+
+const { platform, arch } = process
+
+let nativeBinding = null
+let localFileExisted = false
+let loadError = null
+
+function isMusl() {
+  // For Node 10
+  if (!process.report || typeof process.report.getReport !== 'function') {
+    try {
+      const lddPath = require('child_process').execSync('which ldd').toString().trim()
+      return readFileSync(lddPath, 'utf8').includes('musl')
+    } catch (e) {
+      return true
+    }
+  } else {
+    const { glibcVersionRuntime } = process.report.getReport().header
+    return !glibcVersionRuntime
+  }
+}
+
+switch (platform) {
+  case 'android':
+    switch (arch) {
+      case 'arm64':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.android-arm64.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.android-arm64.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-android-arm64')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      case 'arm':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.android-arm-eabi.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.android-arm-eabi.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-android-arm-eabi')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      default:
+        throw new Error(`Unsupported architecture on Android ${arch}`)
+    }
+    break
+  case 'win32':
+    switch (arch) {
+      case 'x64':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.win32-x64-msvc.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.win32-x64-msvc.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-win32-x64-msvc')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      case 'ia32':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.win32-ia32-msvc.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.win32-ia32-msvc.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-win32-ia32-msvc')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      case 'arm64':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.win32-arm64-msvc.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.win32-arm64-msvc.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-win32-arm64-msvc')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      default:
+        throw new Error(`Unsupported architecture on Windows: ${arch}`)
+    }
+    break
+  case 'darwin':
+    localFileExisted = existsSync(join(__dirname, 'datafusion.darwin-universal.node'))
+    try {
+      if (localFileExisted) {
+        nativeBinding = require('./datafusion.darwin-universal.node')
+      } else {
+        nativeBinding = require('@napi-rs/datafusion-darwin-universal')
+      }
+      break
+    } catch {}
+    switch (arch) {
+      case 'x64':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.darwin-x64.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.darwin-x64.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-darwin-x64')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      case 'arm64':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.darwin-arm64.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.darwin-arm64.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-darwin-arm64')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      default:
+        throw new Error(`Unsupported architecture on macOS: ${arch}`)
+    }
+    break
+  case 'freebsd':
+    if (arch !== 'x64') {
+      throw new Error(`Unsupported architecture on FreeBSD: ${arch}`)
+    }
+    localFileExisted = existsSync(join(__dirname, 'datafusion.freebsd-x64.node'))
+    try {
+      if (localFileExisted) {
+        nativeBinding = require('./datafusion.freebsd-x64.node')
+      } else {
+        nativeBinding = require('@napi-rs/datafusion-freebsd-x64')
+      }
+    } catch (e) {
+      loadError = e
+    }
+    break
+  case 'linux':
+    switch (arch) {
+      case 'x64':
+        if (isMusl()) {
+          localFileExisted = existsSync(join(__dirname, 'datafusion.linux-x64-musl.node'))
+          try {
+            if (localFileExisted) {
+              nativeBinding = require('./datafusion.linux-x64-musl.node')
+            } else {
+              nativeBinding = require('@napi-rs/datafusion-linux-x64-musl')
+            }
+          } catch (e) {
+            loadError = e
+          }
+        } else {
+          localFileExisted = existsSync(join(__dirname, 'datafusion.linux-x64-gnu.node'))
+          try {
+            if (localFileExisted) {
+              nativeBinding = require('./datafusion.linux-x64-gnu.node')
+            } else {
+              nativeBinding = require('@napi-rs/datafusion-linux-x64-gnu')
+            }
+          } catch (e) {
+            loadError = e
+          }
+        }
+        break
+      case 'arm64':
+        if (isMusl()) {
+          localFileExisted = existsSync(join(__dirname, 'datafusion.linux-arm64-musl.node'))
+          try {
+            if (localFileExisted) {
+              nativeBinding = require('./datafusion.linux-arm64-musl.node')
+            } else {
+              nativeBinding = require('@napi-rs/datafusion-linux-arm64-musl')
+            }
+          } catch (e) {
+            loadError = e
+          }
+        } else {
+          localFileExisted = existsSync(join(__dirname, 'datafusion.linux-arm64-gnu.node'))
+          try {
+            if (localFileExisted) {
+              nativeBinding = require('./datafusion.linux-arm64-gnu.node')
+            } else {
+              nativeBinding = require('@napi-rs/datafusion-linux-arm64-gnu')
+            }
+          } catch (e) {
+            loadError = e
+          }
+        }
+        break
+      case 'arm':
+        localFileExisted = existsSync(join(__dirname, 'datafusion.linux-arm-gnueabihf.node'))
+        try {
+          if (localFileExisted) {
+            nativeBinding = require('./datafusion.linux-arm-gnueabihf.node')
+          } else {
+            nativeBinding = require('@napi-rs/datafusion-linux-arm-gnueabihf')
+          }
+        } catch (e) {
+          loadError = e
+        }
+        break
+      default:
+        throw new Error(`Unsupported architecture on Linux: ${arch}`)
+    }
+    break
+  default:
+    throw new Error(`Unsupported OS: ${platform}, architecture: ${arch}`)
+}
+
+if (!nativeBinding) {
+  if (loadError) {
+    throw loadError
+  }
+  throw new Error(`Failed to load native binding`)
+}
+
+const {
+  DataFrame,
+  JoinType,
+  Expr,
+  col,
+  outRefCol,
+  lit,
+  litInt32,
+  litFloat64,
+  litDecimal128,
+  litTimestamp,
+  litDate32,
+  litInterval,
+  interval,
+  litTyped,
+  binaryExpr,
+  and,
+  or,
+  min,
+  max,
+  sum,
+  avg,
+  count,
+  countDistinct,
+  inList,
+  exists,
+  notExists,
+  scalarSubquery,
+  concat,
+  concatWs,
+  random,
+  abs,
+  ceil,
+  floor,
+  round,
+  trunc,
+  signum,
+  sqrt,
+  exp,
+  ln,
+  log2,
+  log10,
+  power,
+  sin,
+  cos,
+  tan,
+  asin,
+  acos,
+  atan,
+  atan2,
+  lower,
+  upper,
+  trim,
+  ltrim,
+  rtrim,
+  substr,
+  replace,
+  splitPart,
+  startsWith,
+  length,
+  lpad,
+  rpad,
+  md5,
+  sha256,
+  regexpMatch,
+  regexpReplace,
+  now,
+  currentDate,
+  currentTime,
+  dateTrunc,
+  datePart,
+  dateBin,
+  toTimestamp,
+  toTimestampMillis,
+  toTimestampMicros,
+  toTimestampSeconds,
+  fromUnixtime,
+  makeArray,
+  arrayAppend,
+  arrayConcat,
+  arrayLength,
+  arrayContains,
+  arrayElement,
+  struct,
+  approxDistinct,
+  approxMedian,
+  approxPercentileCont,
+  approxPercentileContWithWeight,
+  groupingSet,
+  cube,
+  rollup,
+  isNull,
+  isTrue,
+  isNotTrue,
+  isFalse,
+  isNotFalse,
+  isUnknown,
+  isNotUnknown,
+  CaseBuilder,
+  when,
+  caseExpr,
+  WindowFrameUnits,
+  rowNumber,
+  rank,
+  denseRank,
+  percentRank,
+  cumeDist,
+  ntile,
+  lag,
+  lead,
+  firstValue,
+  lastValue,
+  nthValue,
+  Operator,
+  FileCompressionType,
+  ArrowIpcFormat,
+  ReadableStreamFormat,
+  RecordBatchStream,
+  TypeId,
+  TimeUnit,
+  IntervalUnit,
+  SessionContext,
+  ParquetCompression,
+} = nativeBinding
+
+module.exports.DataFrame = DataFrame
+module.exports.JoinType = JoinType
+module.exports.Expr = Expr
+module.exports.col = col
+module.exports.outRefCol = outRefCol
+module.exports.lit = lit
+module.exports.litInt32 = litInt32
+module.exports.litFloat64 = litFloat64
+module.exports.litDecimal128 = litDecimal128
+module.exports.litTimestamp = litTimestamp
+module.exports.litDate32 = litDate32
+module.exports.litInterval = litInterval
+module.exports.interval = interval
+module.exports.litTyped = litTyped
+module.exports.binaryExpr = binaryExpr
+module.exports.and = and
+module.exports.or = or
+module.exports.min = min
+module.exports.max = max
+module.exports.sum = sum
+module.exports.avg = avg
+module.exports.count = count
+module.exports.countDistinct = countDistinct
+module.exports.inList = inList
+module.exports.exists = exists
+module.exports.notExists = notExists
+module.exports.scalarSubquery = scalarSubquery
+module.exports.concat = concat
+module.exports.concatWs = concatWs
+module.exports.random = random
+module.exports.abs = abs
+module.exports.ceil = ceil
+module.exports.floor = floor
+module.exports.round = round
+module.exports.trunc = trunc
+module.exports.signum = signum
+module.exports.sqrt = sqrt
+module.exports.exp = exp
+module.exports.ln = ln
+module.exports.log2 = log2
+module.exports.log10 = log10
+module.exports.power = power
+module.exports.sin = sin
+module.exports.cos = cos
+module.exports.tan = tan
+module.exports.asin = asin
+module.exports.acos = acos
+module.exports.atan = atan
+module.exports.atan2 = atan2
+module.exports.lower = lower
+module.exports.upper = upper
+module.exports.trim = trim
+module.exports.ltrim = ltrim
+module.exports.rtrim = rtrim
+module.exports.substr = substr
+module.exports.replace = replace
+module.exports.splitPart = splitPart
+module.exports.startsWith = startsWith
+module.exports.length = length
+module.exports.lpad = lpad
+module.exports.rpad = rpad
+module.exports.md5 = md5
+module.exports.sha256 = sha256
+module.exports.regexpMatch = regexpMatch
+module.exports.regexpReplace = regexpReplace
+module.exports.now = now
+module.exports.currentDate = currentDate
+module.exports.currentTime = currentTime
+module.exports.dateTrunc = dateTrunc
+module.exports.datePart = datePart
+module.exports.dateBin = dateBin
+module.exports.toTimestamp = toTimestamp
+module.exports.toTimestampMillis = toTimestampMillis
+module.exports.toTimestampMicros = toTimestampMicros
+module.exports.toTimestampSeconds = toTimestampSeconds
+module.exports.fromUnixtime = fromUnixtime
+module.exports.makeArray = makeArray
+module.exports.arrayAppend = arrayAppend
+module.exports.arrayConcat = arrayConcat
+module.exports.arrayLength = arrayLength
+module.exports.arrayContains = arrayContains
+module.exports.arrayElement = arrayElement
+module.exports.struct = struct
+module.exports.approxDistinct = approxDistinct
+module.exports.approxMedian = approxMedian
+module.exports.approxPercentileCont = approxPercentileCont
+module.exports.approxPercentileContWithWeight = approxPercentileContWithWeight
+module.exports.groupingSet = groupingSet
+module.exports.cube = cube
+module.exports.rollup = rollup
+module.exports.isNull = isNull
+module.exports.isTrue = isTrue
+module.exports.isNotTrue = isNotTrue
+module.exports.isFalse = isFalse
+module.exports.isNotFalse = isNotFalse
+module.exports.isUnknown = isUnknown
+module.exports.isNotUnknown = isNotUnknown
+module.exports.CaseBuilder = CaseBuilder
+module.exports.when = when
+module.exports.caseExpr = caseExpr
+module.exports.WindowFrameUnits = WindowFrameUnits
+module.exports.rowNumber = rowNumber
+module.exports.rank = rank
+module.exports.denseRank = denseRank
+module.exports.percentRank = percentRank
+module.exports.cumeDist = cumeDist
+module.exports.ntile = ntile
+module.exports.lag = lag
+module.exports.lead = lead
+module.exports.firstValue = firstValue
+module.exports.lastValue = lastValue
+module.exports.nthValue = nthValue
+module.exports.Operator = Operator
+module.exports.FileCompressionType = FileCompressionType
+module.exports.ArrowIpcFormat = ArrowIpcFormat
+module.exports.ReadableStreamFormat = ReadableStreamFormat
+module.exports.RecordBatchStream = RecordBatchStream
+module.exports.TypeId = TypeId
+module.exports.TimeUnit = TimeUnit
+module.exports.IntervalUnit = IntervalUnit
+module.exports.SessionContext = SessionContext
+module.exports.ParquetCompression = ParquetCompression
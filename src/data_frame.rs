@@ -1,19 +1,24 @@
+use std::sync::Arc;
+
+use datafusion::arrow::compute::concat_batches;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
 use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
 use napi_derive::napi;
 
 use crate::expr::Expr;
-
-macro_rules! take_inner {
-  ($self:ident, $inner:ident, $op:expr) => {{
-    if let Some($inner) = std::mem::replace(&mut $self.inner, None) {
-      $self.inner = Some($op.map_err(anyhow::Error::from)?);
-    }
-  }};
-}
+use crate::record_batch::{
+  to_arrow_ipc, ArrowIpcFormat, ArrowIpcOptions, CollectOptions, Columns, Rows,
+};
+use crate::record_batch_stream::{
+  Encoder, ReadableStreamFormat, ReadableStreamOptions, RecordBatchStream,
+};
+use crate::writer::{write, CsvWriteOptions, FileFormat, JsonWriteOptions, ParquetWriteOptions};
 
 #[napi]
 pub struct DataFrame {
-  pub(crate) inner: Option<datafusion::prelude::DataFrame>,
+  pub(crate) inner: datafusion::prelude::DataFrame,
 }
 
 #[napi]
@@ -37,9 +42,8 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.selectColumns(&["a", "b"])?;
   /// ```
-  pub fn select_columns(&mut self, columns: Vec<&str>) -> Result<&Self> {
-    take_inner!(self, inner, inner.select_columns(&columns));
-    Ok(self)
+  pub fn select_columns(&self, columns: Vec<&str>) -> Result<DataFrame> {
+    self.transform(|inner| inner.select_columns(&columns))
   }
 
   #[napi]
@@ -51,34 +55,63 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.select([col("a") * col("b"), col("c")]);
   /// ```
-  pub fn select(&mut self, expr_list: Vec<&Expr>) -> Result<&Self> {
-    take_inner!(
-      self,
-      inner,
-      inner.select(expr_list.iter().map(|e| e.value()).collect())
-    );
-    Ok(self)
+  pub fn select(&self, expr_list: Vec<&Expr>) -> Result<DataFrame> {
+    self.transform(|inner| inner.select(expr_list.iter().map(|e| e.value()).collect()))
   }
 
   #[napi]
   /// Filter a DataFrame to only include rows that match the specified filter expression.
-  pub fn filter(&mut self, expr: &Expr) -> Result<&Self> {
-    take_inner!(self, inner, inner.filter(expr.value()));
-    Ok(self)
+  pub fn filter(&self, expr: &Expr) -> Result<DataFrame> {
+    self.transform(|inner| inner.filter(expr.value()))
   }
 
   #[napi]
   /// Perform an aggregate query with optional grouping expressions.
-  pub fn aggregate(&mut self, group_expr: Vec<&Expr>, aggr_expr: Vec<&Expr>) -> Result<&Self> {
-    take_inner!(
-      self,
-      inner,
+  pub fn aggregate(&self, group_expr: Vec<&Expr>, aggr_expr: Vec<&Expr>) -> Result<DataFrame> {
+    self.transform(|inner| {
       inner.aggregate(
         group_expr.iter().map(|e| e.value()).collect(),
-        aggr_expr.iter().map(|e| e.value()).collect()
+        aggr_expr.iter().map(|e| e.value()).collect(),
       )
-    );
-    Ok(self)
+    })
+  }
+
+  #[napi]
+  /// Append the results of window expressions as new columns, keeping all the existing columns.
+  ///
+  /// ```
+  /// import { col, rowNumber, SessionContext, sum } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// df.window([
+  ///   rowNumber().over({ orderBy: [col('a')] }).alias('row_number'),
+  ///   sum(col('b')).over({ orderBy: [col('a')] }).alias('running_total'),
+  /// ]);
+  /// ```
+  pub fn window(&self, window_exprs: Vec<&Expr>) -> Result<DataFrame> {
+    self.transform(|inner| {
+      let mut exprs = inner
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| datafusion::prelude::Expr::Column(f.qualified_column()))
+        .collect::<Vec<_>>();
+      exprs.extend(window_exprs.iter().map(|e| e.value()));
+      inner.select(exprs)
+    })
+  }
+
+  #[napi]
+  /// Expand the list `column` into one row per element, the values of the other columns are repeated.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.sql('SELECT 1 AS id, make_array(1, 2, 3) AS values');
+  /// df.unnest('values'); // 3 rows
+  /// ```
+  pub fn unnest(&self, column: String) -> Result<DataFrame> {
+    self.transform(|inner| inner.unnest_column(&column))
   }
 
   #[napi]
@@ -93,13 +126,8 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.limit(0, Some(100));
   /// ```
-  pub fn limit(&mut self, skip: i64, fetch: Option<i64>) -> Result<&Self> {
-    take_inner!(
-      self,
-      inner,
-      inner.limit(skip as usize, fetch.map(|v| v as usize))
-    );
-    Ok(self)
+  pub fn limit(&self, skip: i64, fetch: Option<i64>) -> Result<DataFrame> {
+    self.transform(|inner| inner.limit(skip as usize, fetch.map(|v| v as usize)))
   }
 
   #[napi]
@@ -113,9 +141,8 @@ impl DataFrame {
   /// const d2 = df.clone();
   /// df.union(d2);
   /// ```
-  pub fn union(&mut self, dataframe: &DataFrame) -> Result<&Self> {
-    take_inner!(self, inner, inner.union(dataframe.value()));
-    Ok(self)
+  pub fn union(&self, dataframe: &DataFrame) -> Result<DataFrame> {
+    self.transform(|inner| inner.union(dataframe.value()))
   }
 
   #[napi]
@@ -128,9 +155,8 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.distinct();
   /// ```
-  pub fn union_distinct(&mut self, dataframe: &DataFrame) -> Result<&Self> {
-    take_inner!(self, inner, inner.union_distinct(dataframe.value()));
-    Ok(self)
+  pub fn union_distinct(&self, dataframe: &DataFrame) -> Result<DataFrame> {
+    self.transform(|inner| inner.union_distinct(dataframe.value()))
   }
 
   #[napi]
@@ -142,9 +168,8 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.distinct(d2);
   /// ```
-  pub fn distinct(&mut self) -> Result<&Self> {
-    take_inner!(self, inner, inner.distinct());
-    Ok(self)
+  pub fn distinct(&self) -> Result<DataFrame> {
+    self.transform(|inner| inner.distinct())
   }
 
   #[napi]
@@ -157,15 +182,11 @@ impl DataFrame {
   /// const df = await ctx.readCsv('tests/data/example.csv');
   /// df.sort([col('a').sort(true, true), col('b').sort(false, false)]);
   /// ```
-  pub fn sort(&mut self, expr: Vec<&Expr>) -> Result<&Self> {
-    take_inner!(
-      self,
-      inner,
-      inner.sort(expr.iter().map(|e| e.value()).collect())
-    );
-    Ok(self)
+  pub fn sort(&self, expr: Vec<&Expr>) -> Result<DataFrame> {
+    self.transform(|inner| inner.sort(expr.iter().map(|e| e.value()).collect()))
   }
 
+  #[napi]
   /// Join this DataFrame with another DataFrame using the specified columns as join keys.
   ///
   /// Filter expression expected to contain non-equality predicates that can not be pushed
@@ -173,7 +194,7 @@ impl DataFrame {
   /// In case of outer join, filter applied to only matched rows.
   ///
   /// ```
-  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// import { col, JoinType, SessionContext } from '@napi-rs/datafusion';
   /// const ctx = new SessionContext();
   /// const left = await ctx.readCsv('tests/data/example.csv');
   /// const right = (await ctx.readCsv('tests/data/example.csv'))
@@ -182,28 +203,64 @@ impl DataFrame {
   ///     col('b').alias('b2'),
   ///     col('c').alias('c2'),
   ///   ]);
-  /// const batches = await left.join(right, JoinType::Inner, ['a', 'b'], ['a2', 'b2']).collect();
+  /// const batches = await left.join(right, JoinType.Inner, ['a', 'b'], ['a2', 'b2']).collect();
   /// ```
   pub fn join(
-    &mut self,
+    &self,
     right: &DataFrame,
     join_type: JoinType,
     left_cols: Vec<&str>,
     right_cols: Vec<&str>,
     filter: Option<&Expr>,
-  ) -> Result<&Self> {
-    take_inner!(
-      self,
-      inner,
+  ) -> Result<DataFrame> {
+    self.transform(|inner| {
       inner.join(
         right.value(),
         join_type.into(),
         left_cols.as_ref(),
         right_cols.as_ref(),
-        filter.map(|f| f.value())
+        filter.map(|f| f.value()),
       )
-    );
-    Ok(self)
+    })
+  }
+
+  #[napi]
+  /// Join this DataFrame with another DataFrame using arbitrary expressions as join conditions,
+  /// all the expressions in `onExprs` must be true for a pair of rows to match.
+  ///
+  /// Equality predicates between the columns of both sides are executed as hash join keys,
+  /// the other predicates, like ranges, are applied as the join filter.
+  ///
+  /// ```
+  /// import { binaryExpr, col, JoinType, Operator, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const left = await ctx.readCsv('tests/data/example.csv');
+  /// const right = (await ctx.readCsv('tests/data/example.csv'))
+  ///   .select([
+  ///     col('a').alias('a2'),
+  ///     col('b').alias('b2'),
+  ///     col('c').alias('c2'),
+  ///   ]);
+  /// const batches = await left
+  ///   .joinOn(right, JoinType.Left, [
+  ///     binaryExpr(col('a'), Operator.Eq, col('a2')),
+  ///     binaryExpr(col('b'), Operator.Lt, col('c2')),
+  ///   ])
+  ///   .collect();
+  /// ```
+  pub fn join_on(
+    &self,
+    right: &DataFrame,
+    join_type: JoinType,
+    on_exprs: Vec<&Expr>,
+  ) -> Result<DataFrame> {
+    self.transform(|inner| {
+      inner.join_on(
+        right.value(),
+        join_type.into(),
+        on_exprs.iter().map(|e| e.value()),
+      )
+    })
   }
 
   #[napi]
@@ -217,23 +274,223 @@ impl DataFrame {
   /// await df.show();
   /// ```
   pub async fn show(&self) -> Result<()> {
-    self
-      .inner
-      .clone()
-      .unwrap()
-      .show()
-      .await
-      .map_err(anyhow::Error::from)?;
+    self.value().show().await.map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi(ts_return_type = "Promise<Array<Record<string, any>>>")]
+  /// Execute this `DataFrame` and collect the results into an `Array` of row objects
+  /// keyed by column name.
+  ///
+  /// Values are converted from Arrow types into JavaScript types:
+  ///
+  /// - `null` values => `null`
+  /// - `Boolean` => `boolean`
+  /// - `Int8` ~ `Int32`, `UInt8` ~ `UInt32`, `Float32`, `Float64` => `number`
+  /// - `Int64`, `UInt64` => `number`, or `BigInt` if `int64AsBigInt` is set
+  /// - `Utf8`, `LargeUtf8` => `string`
+  /// - `Binary`, `LargeBinary` => `Buffer`
+  /// - other types are formatted into their `string` representation
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// const rows = await df.collect({ int64AsBigInt: true });
+  /// ```
+  pub async fn collect(&self, options: Option<CollectOptions>) -> Result<Rows> {
+    let batches = self.value().collect().await.map_err(anyhow::Error::from)?;
+    Ok(Rows {
+      batches,
+      options: options.unwrap_or_default(),
+    })
+  }
+
+  #[napi(
+    ts_return_type = "Promise<{ columns: Record<string, Int8Array | Int16Array | Int32Array | BigInt64Array | Uint8Array | Uint16Array | Uint32Array | BigUint64Array | Float32Array | Float64Array | Array<string | null>>, validity: Record<string, Uint8Array | null> }>"
+  )]
+  /// Execute this `DataFrame` and collect the results column by column.
+  ///
+  /// `columns` maps every column name to its values, built from the Arrow buffers:
+  ///
+  /// - `Int8`, `Int16`, `Int32` => `Int8Array`, `Int16Array`, `Int32Array`
+  /// - `UInt8`, `UInt16`, `UInt32` => `Uint8Array`, `Uint16Array`, `Uint32Array`
+  /// - `Int64`, `UInt64` => `BigInt64Array`, `BigUint64Array`
+  /// - `Float32`, `Float64` => `Float32Array`, `Float64Array`
+  /// - `Boolean` => `Uint8Array` of `0` and `1`
+  /// - `Date32` => `Int32Array` of days since UNIX epoch
+  /// - `Date64` => `BigInt64Array` of milliseconds since UNIX epoch
+  /// - `Timestamp` => `BigInt64Array` of the raw values since UNIX epoch in the `unit` of the column,
+  ///   the timezone is not applied
+  /// - `Utf8`, `LargeUtf8` => `Array<string | null>`
+  /// - other types are formatted into `Array<string | null>`
+  ///
+  /// `validity` maps every column name to its validity bitmap, bit `i % 8` of byte `i / 8`
+  /// is set if the value at `i` is not null. It's `null` if the column contains no nulls.
+  /// The values of the null slots in the `TypedArray`s are unspecified.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// const { columns, validity } = await df.collectColumns();
+  /// ```
+  pub async fn collect_columns(&self) -> Result<Columns> {
+    let (schema, batches) = collect_with_schema(self.value()).await?;
+    let batch = concat_batches(&schema, &batches).map_err(anyhow::Error::from)?;
+    Ok(Columns { batch })
+  }
+
+  #[napi]
+  /// Execute this `DataFrame` and serialize the results with Arrow IPC, the `Buffer` can be
+  /// passed to `tableFromIPC` of `apache-arrow` or written to disk as is.
+  ///
+  /// ```
+  /// import { tableFromIPC } from 'apache-arrow';
+  /// import { ArrowIpcFormat, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// const table = tableFromIPC(await df.toArrowIpc({ format: ArrowIpcFormat.Stream }));
+  /// ```
+  pub async fn to_arrow_ipc(&self, options: Option<ArrowIpcOptions>) -> Result<Buffer> {
+    let (schema, batches) = collect_with_schema(self.value()).await?;
+    let format = options
+      .and_then(|o| o.format)
+      .unwrap_or(ArrowIpcFormat::Stream);
+    Ok(to_arrow_ipc(&schema, &batches, format)?.into())
+  }
+
+  #[napi(ts_return_type = "AsyncIterableIterator<Array<Record<string, any>>>")]
+  /// Execute this `DataFrame` lazily and iterate over the results one `RecordBatch` at a time,
+  /// each yielded as an `Array` of row objects, see `collect` for the type mapping.
+  ///
+  /// The next batch is only computed when the consumer asks for it, so large results can be
+  /// consumed without holding them in memory.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// for await (const rows of df.executeStream()) {
+  ///   console.log(rows);
+  /// }
+  /// ```
+  pub fn execute_stream(&self, env: Env, options: Option<CollectOptions>) -> Result<JsObject> {
+    RecordBatchStream::create(
+      env,
+      self.value(),
+      Encoder::Rows(options.unwrap_or_default()),
+    )
+  }
+
+  #[napi(skip_typescript)]
+  /// The chunks of `toReadableStream`, which wraps them into a `stream.Readable` in `index.js`.
+  ///
+  /// The `objects` format yields an `Array` of row objects per `RecordBatch`, the other formats yield `Buffer`s.
+  pub fn to_readable_chunks(
+    &self,
+    env: Env,
+    options: Option<ReadableStreamOptions>,
+  ) -> Result<JsObject> {
+    let (format, int64_as_big_int) = options
+      .map(|o| (o.format, o.int64_as_big_int))
+      .unwrap_or_default();
+    RecordBatchStream::create(
+      env,
+      self.value(),
+      Encoder::new(
+        format.unwrap_or(ReadableStreamFormat::Objects),
+        CollectOptions { int64_as_big_int },
+      ),
+    )
+  }
+
+  #[napi]
+  /// Execute this `DataFrame` and write the results into Parquet files.
+  ///
+  /// ```
+  /// import { ParquetCompression, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// await df.writeParquet('example.parquet', { compression: ParquetCompression.Zstd, maxRowGroupSize: 65536 });
+  /// ```
+  pub async fn write_parquet(
+    &self,
+    path: String,
+    options: Option<ParquetWriteOptions>,
+  ) -> Result<()> {
+    let options = options.unwrap_or_default();
+    let single_file_output = options.single_file_output.unwrap_or(true);
+    write(self.value(), &path, options.into(), single_file_output).await?;
+    Ok(())
+  }
+
+  #[napi]
+  /// Execute this `DataFrame` and write the results into CSV files.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// await df.writeCsv('output', { delimiter: ';', singleFileOutput: false });
+  /// ```
+  pub async fn write_csv(&self, path: String, options: Option<CsvWriteOptions>) -> Result<()> {
+    let options = options.unwrap_or_default();
+    let single_file_output = options.single_file_output.unwrap_or(true);
+    write(
+      self.value(),
+      &path,
+      FileFormat::try_from(options)?,
+      single_file_output,
+    )
+    .await?;
+    Ok(())
+  }
+
+  #[napi]
+  /// Execute this `DataFrame` and write the results into newline delimited JSON files.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// await df.writeJson('example.json');
+  /// ```
+  pub async fn write_json(&self, path: String, options: Option<JsonWriteOptions>) -> Result<()> {
+    let single_file_output = options.and_then(|o| o.single_file_output).unwrap_or(true);
+    write(self.value(), &path, FileFormat::Json, single_file_output).await?;
     Ok(())
   }
 
   pub(crate) fn value(&self) -> datafusion::prelude::DataFrame {
-    if let Some(value) = &self.inner {
-      value.clone()
-    } else {
-      unreachable!("DataFrame must have a value")
-    }
+    self.inner.clone()
   }
+
+  /// Apply `f` to the plan of this `DataFrame` and wrap the result into a new `DataFrame`,
+  /// leaving this one untouched.
+  fn transform(
+    &self,
+    f: impl FnOnce(
+      datafusion::prelude::DataFrame,
+    ) -> datafusion::error::Result<datafusion::prelude::DataFrame>,
+  ) -> Result<DataFrame> {
+    Ok(DataFrame {
+      inner: f(self.value()).map_err(anyhow::Error::from)?,
+    })
+  }
+}
+
+/// Collect the results of `df` along with their schema, the schema of `df` is used if there is no
+/// [`RecordBatch`] in the results.
+async fn collect_with_schema(
+  df: datafusion::prelude::DataFrame,
+) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+  let schema: SchemaRef = Arc::new(df.schema().into());
+  let batches = df.collect().await.map_err(anyhow::Error::from)?;
+  Ok((
+    batches.first().map(|b| b.schema()).unwrap_or(schema),
+    batches,
+  ))
 }
 
 #[napi]
@@ -1,13 +1,21 @@
+use datafusion::arrow::datatypes::{DataType, IntervalMonthDayNanoType};
+use datafusion::common::{DFSchema, TableReference};
+use datafusion::config::ConfigOptions;
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::{
+  AggregateUDF, BuiltinScalarFunction, ExprSchemable, ScalarUDF, TableSource,
+};
+use datafusion::scalar::ScalarValue;
+use datafusion::sql::planner::{ContextProvider, PlannerContext, SqlToRel};
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+use datafusion::sql::sqlparser::tokenizer::Token;
 use napi::bindgen_prelude::*;
+use napi::{Env, JsBigInt, JsBuffer, JsDate, JsObject, JsUnknown, NapiRaw, ValueType};
 use napi_derive::napi;
 
-macro_rules! take_inner {
-  ($self:ident, $inner:ident, $lit:expr) => {{
-    if let Some($inner) = std::mem::replace(&mut $self.inner, None) {
-      $self.inner = Some($lit);
-    }
-  }};
-}
+use crate::data_frame::DataFrame;
+use crate::schema::{to_arrow_schema, DataType as JsDataType, Field, TimeUnit};
 
 #[napi]
 pub struct Expr {
@@ -16,116 +24,274 @@ pub struct Expr {
 
 #[napi]
 impl Expr {
+  #[napi(ts_args_type = "value: string | number | bigint | boolean | Date | Buffer | null")]
+  /// Create a literal expression, see [`lit`] for the mapping of the value types
+  pub fn lit(value: JsUnknown) -> Result<Self> {
+    lit(value)
+  }
+
   #[napi]
-  /// Create a literal expression
-  pub fn lit(value: String) -> Self {
-    Self {
-      inner: Some(datafusion::prelude::lit(value)),
-    }
+  /// Parse the SQL expression `sql`, with the columns resolved against the schema of `df`
+  ///
+  /// ```
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// const rule = Expr.parse("a + b * 2 > 10 AND c LIKE 'x%'", df);
+  /// df.filter(rule);
+  /// ```
+  pub fn parse(sql: String, df: &DataFrame) -> Result<Self> {
+    Ok(Expr {
+      inner: Some(parse_sql_expr(&sql, df.inner.schema())?),
+    })
+  }
+
+  #[napi]
+  /// Parse the SQL expression `sql`, with the columns resolved against `schema`
+  pub fn parse_with_schema(sql: String, schema: Vec<Field>) -> Result<Self> {
+    let schema = to_df_schema(&schema)?;
+    Ok(Expr {
+      inner: Some(parse_sql_expr(&sql, &schema)?),
+    })
   }
 
   #[napi]
   /// Return `self AS name` alias expression
-  pub fn alias(&mut self, name: String) -> &Self {
-    take_inner!(self, inner, inner.alias(name));
-    self
+  pub fn alias(&self, name: String) -> Expr {
+    self.transform(|inner| inner.alias(name))
+  }
+
+  #[napi]
+  /// Return `self = other`
+  pub fn eq(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.eq(other.value()))
+  }
+
+  #[napi]
+  /// Return `self != other`
+  pub fn not_eq(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.not_eq(other.value()))
+  }
+
+  #[napi]
+  /// Return `self < other`
+  pub fn lt(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.lt(other.value()))
   }
 
   #[napi]
   /// Return `self <= other`
-  pub fn lt_eq(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.lt_eq(other.value()));
-    self
+  pub fn lt_eq(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.lt_eq(other.value()))
+  }
+
+  #[napi]
+  /// Return `self > other`
+  pub fn gt(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.gt(other.value()))
+  }
+
+  #[napi]
+  /// Return `self >= other`
+  pub fn gt_eq(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.gt_eq(other.value()))
+  }
+
+  #[napi]
+  /// Return `self + other`
+  pub fn add(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner + other.value())
+  }
+
+  #[napi]
+  /// Return `self - other`
+  pub fn sub(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner - other.value())
+  }
+
+  #[napi]
+  /// Return `self * other`
+  pub fn mul(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner * other.value())
+  }
+
+  #[napi]
+  /// Return `self / other`
+  pub fn div(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner / other.value())
+  }
+
+  #[napi]
+  /// Return `-self`
+  pub fn neg(&self) -> Expr {
+    self.transform(|inner| datafusion::prelude::Expr::Negative(Box::new(inner)))
+  }
+
+  #[napi]
+  /// Return `self BETWEEN low AND high`
+  pub fn between(&self, low: &Expr, high: &Expr) -> Expr {
+    self.transform(|inner| {
+      datafusion::prelude::Expr::Between(datafusion::logical_expr::Between::new(
+        Box::new(inner),
+        false,
+        Box::new(low.value()),
+        Box::new(high.value()),
+      ))
+    })
+  }
+
+  #[napi]
+  /// Return `self NOT BETWEEN low AND high`
+  pub fn not_between(&self, low: &Expr, high: &Expr) -> Expr {
+    self.transform(|inner| {
+      datafusion::prelude::Expr::Between(datafusion::logical_expr::Between::new(
+        Box::new(inner),
+        true,
+        Box::new(low.value()),
+        Box::new(high.value()),
+      ))
+    })
+  }
+
+  #[napi]
+  /// Return `self IS DISTINCT FROM other`, which treats `NULL` as a comparable value
+  pub fn is_distinct_from(&self, other: &Expr) -> Expr {
+    self.transform(|inner| {
+      datafusion::prelude::binary_expr(
+        inner,
+        datafusion::logical_expr::Operator::IsDistinctFrom,
+        other.value(),
+      )
+    })
+  }
+
+  #[napi]
+  /// Return `self IS NOT DISTINCT FROM other`, which treats `NULL` as a comparable value
+  pub fn is_not_distinct_from(&self, other: &Expr) -> Expr {
+    self.transform(|inner| {
+      datafusion::prelude::binary_expr(
+        inner,
+        datafusion::logical_expr::Operator::IsNotDistinctFrom,
+        other.value(),
+      )
+    })
   }
 
   #[napi]
   /// Return `self && other`
-  pub fn and(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.and(other.value()));
-    self
+  pub fn and(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.and(other.value()))
   }
 
   #[napi]
   /// Return `self || other`
-  pub fn or(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.or(other.value()));
-    self
+  pub fn or(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.or(other.value()))
   }
 
   #[napi]
   /// Return `!self`
-  pub fn not(&mut self) -> &Self {
-    take_inner!(self, inner, inner.not());
-    self
+  pub fn not(&self) -> Expr {
+    self.transform(|inner| inner.not())
   }
 
   #[napi]
   /// Calculate the modulus of two expressions.
   /// Return `self % other`
-  pub fn modulus(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.modulus(other.value()));
-    self
+  pub fn modulus(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.modulus(other.value()))
   }
 
   #[napi]
   /// Return `self LIKE other`
-  pub fn like(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.like(other.value()));
-    self
+  pub fn like(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.like(other.value()))
   }
 
   #[napi]
   /// Return `self NOT LIKE other`
-  pub fn not_like(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.not_like(other.value()));
-    self
+  pub fn not_like(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.not_like(other.value()))
   }
 
   #[napi]
   /// Return `self ILIKE other`
-  pub fn ilike(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.ilike(other.value()));
-    self
+  pub fn ilike(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.ilike(other.value()))
   }
 
   #[napi]
   /// Return `self NOT ILIKE other`
-  pub fn not_ilike(&mut self, other: &Expr) -> &Self {
-    take_inner!(self, inner, inner.not_ilike(other.value()));
-    self
+  pub fn not_ilike(&self, other: &Expr) -> Expr {
+    self.transform(|inner| inner.not_ilike(other.value()))
+  }
+
+  #[napi]
+  /// Return `CAST(self AS dataType)`, which fails the execution if a value can not be cast.
+  ///
+  /// ```
+  /// const expr = col('price').cast({ typeId: TypeId.Decimal128, precision: 10, scale: 2 });
+  /// ```
+  pub fn cast(&self, data_type: JsDataType) -> Result<Expr> {
+    let data_type = DataType::try_from(&data_type)?;
+    Ok(self.transform(|inner| datafusion::logical_expr::cast(inner, data_type)))
+  }
+
+  #[napi]
+  /// Return `TRY_CAST(self AS dataType)`, which returns `NULL` if a value can not be cast.
+  pub fn try_cast(&self, data_type: JsDataType) -> Result<Expr> {
+    let data_type = DataType::try_from(&data_type)?;
+    Ok(self.transform(|inner| datafusion::logical_expr::try_cast(inner, data_type)))
+  }
+
+  #[napi]
+  /// Return the field `name` of the struct
+  ///
+  /// Looking up a key of a `Map` is not supported by DataFusion 25, planning fails with
+  /// an error if `self` is a `Map`.
+  ///
+  /// ```
+  /// const city = col('payload').field('address').field('city');
+  /// ```
+  pub fn field(&self, name: String) -> Expr {
+    self.transform(|inner| get_indexed_field(inner, ScalarValue::Utf8(Some(name))))
   }
 
   #[napi]
   /// Remove an alias from an expression if one exists.
-  pub fn unalias(&mut self) -> &Self {
-    take_inner!(self, inner, inner.unalias());
-    self
+  pub fn unalias(&self) -> Expr {
+    self.transform(|inner| inner.unalias())
   }
 
   #[napi]
   /// Return `self IN <list>` if `negated` is false, otherwise
   /// return `self NOT IN <list>`.a
-  pub fn in_list(&mut self, list: Vec<&Expr>, negated: bool) -> &Self {
-    take_inner!(
-      self,
-      inner,
-      inner.in_list(list.into_iter().map(|e| e.value()).collect(), negated)
-    );
-    self
+  pub fn in_list(&self, list: Vec<&Expr>, negated: bool) -> Expr {
+    self.transform(|inner| inner.in_list(list.into_iter().map(|e| e.value()).collect(), negated))
+  }
+
+  #[napi]
+  /// Return `self IN (<subquery>)` if `negated` is false, otherwise
+  /// return `self NOT IN (<subquery>)`, `subquery` must return a single column.
+  pub fn in_subquery(&self, subquery: &DataFrame, negated: bool) -> Expr {
+    let subquery = subquery_plan(subquery);
+    self.transform(|inner| {
+      if negated {
+        datafusion::prelude::not_in_subquery(inner, subquery)
+      } else {
+        datafusion::prelude::in_subquery(inner, subquery)
+      }
+    })
   }
 
   #[napi]
   /// Return `IsNull(Box(self))
-  pub fn is_null(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_null());
-    self
+  pub fn is_null(&self) -> Expr {
+    self.transform(|inner| inner.is_null())
   }
 
   #[napi]
   /// Return `IsNotNull(Box(self))
-  pub fn is_not_null(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_not_null());
-    self
+  pub fn is_not_null(&self) -> Expr {
+    self.transform(|inner| inner.is_not_null())
   }
 
   #[napi]
@@ -134,51 +300,159 @@ impl Expr {
   /// ```
   /// const sortExpr = col('foo').sort(true, true); // SORT ASC NULLS_FIRST
   /// ```
-  pub fn sort(&mut self, asc: bool, nulls_first: bool) -> &Self {
-    take_inner!(self, inner, inner.sort(asc, nulls_first));
-    self
+  pub fn sort(&self, asc: bool, nulls_first: bool) -> Expr {
+    self.transform(|inner| inner.sort(asc, nulls_first))
   }
 
   #[napi]
   /// Return `IsTrue(Box(self))`
-  pub fn is_true(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_true());
-    self
+  pub fn is_true(&self) -> Expr {
+    self.transform(|inner| inner.is_true())
   }
 
   #[napi]
   /// Return `IsNotTrue(Box(self))`
-  pub fn is_not_true(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_not_true());
-    self
+  pub fn is_not_true(&self) -> Expr {
+    self.transform(|inner| inner.is_not_true())
   }
 
   #[napi]
   /// Return `IsFalse(Box(self))`
-  pub fn is_false(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_false());
-    self
+  pub fn is_false(&self) -> Expr {
+    self.transform(|inner| inner.is_false())
   }
 
   #[napi]
   /// Return `IsNotFalse(Box(self))`
-  pub fn is_not_false(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_not_false());
-    self
+  pub fn is_not_false(&self) -> Expr {
+    self.transform(|inner| inner.is_not_false())
   }
 
   #[napi]
   /// Return `IsUnknown(Box(self))`
-  pub fn is_unknown(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_unknown());
-    self
+  pub fn is_unknown(&self) -> Expr {
+    self.transform(|inner| inner.is_unknown())
   }
 
   #[napi]
   /// Return `IsNotUnknown(Box(self))`
-  pub fn is_not_unknown(&mut self) -> &Self {
-    take_inner!(self, inner, inner.is_not_unknown());
-    self
+  pub fn is_not_unknown(&self) -> Expr {
+    self.transform(|inner| inner.is_not_unknown())
+  }
+
+  #[napi(
+    ts_args_type = "options?: { partitionBy?: Array<Expr>, orderBy?: Array<Expr>, frame?: WindowFrame } | undefined | null"
+  )]
+  /// Turn a window function, or an aggregate function, into a window expression
+  /// computed over the partitions and frame described by `options`.
+  ///
+  /// `orderBy` accepts sort expressions, other expressions are sorted in ascending order with nulls last.
+  ///
+  /// ```
+  /// const runningTotal = sum(col('amount')).over({
+  ///   partitionBy: [col('user_id')],
+  ///   orderBy: [col('created_at').sort(true, false)],
+  ///   frame: { units: WindowFrameUnits.Rows, start: -Infinity, end: 0 },
+  /// });
+  /// const ranking = rank().over({ partitionBy: [col('group')], orderBy: [col('score').sort(false, false)] });
+  /// ```
+  pub fn over(&self, env: Env, options: Option<JsObject>) -> Result<Expr> {
+    use datafusion::logical_expr::expr::{AggregateFunction, WindowFunction};
+
+    let (partition_by, order_by, frame) = match options {
+      Some(options) => (
+        to_exprs(&env, options.get_named_property_unchecked("partitionBy")?)?,
+        to_exprs(&env, options.get_named_property_unchecked("orderBy")?)?
+          .into_iter()
+          .map(|e| match e {
+            datafusion::prelude::Expr::Sort(_) => e,
+            e => e.sort(true, false),
+          })
+          .collect::<Vec<_>>(),
+        options.get_named_property_unchecked::<Option<WindowFrame>>("frame")?,
+      ),
+      None => (vec![], vec![], None),
+    };
+    let window_frame = match frame {
+      Some(frame) => frame.into(),
+      None => datafusion::logical_expr::WindowFrame::new(!order_by.is_empty()),
+    };
+    let (fun, args) = match self.value() {
+      datafusion::prelude::Expr::WindowFunction(WindowFunction { fun, args, .. }) => (fun, args),
+      datafusion::prelude::Expr::AggregateFunction(AggregateFunction {
+        fun,
+        args,
+        distinct: false,
+        filter: None,
+        ..
+      }) => (
+        datafusion::logical_expr::WindowFunction::AggregateFunction(fun),
+        args,
+      ),
+      expr => {
+        return Err(
+          anyhow::anyhow!(
+            "`over` requires a window function or an aggregate function, got `{expr}`"
+          )
+          .into(),
+        )
+      }
+    };
+    Ok(Expr {
+      inner: Some(datafusion::prelude::Expr::WindowFunction(
+        WindowFunction::new(fun, args, partition_by, order_by, window_frame),
+      )),
+    })
+  }
+
+  #[napi]
+  /// Format the expression like DataFusion does in the plans and the column names
+  ///
+  /// ```
+  /// col('a').add(lit(1)).toString(); // 'a + Int64(1)'
+  /// ```
+  #[allow(clippy::inherent_to_string)]
+  pub fn to_string(&self) -> String {
+    self.value().to_string()
+  }
+
+  #[napi(js_name = "toJSON")]
+  /// The tree of the expression, `JSON.stringify(expr)` serializes it
+  pub fn to_json(&self) -> Result<ExprNode> {
+    Ok(ExprNode::try_from(&self.value()).map_err(anyhow::Error::from)?)
+  }
+
+  #[napi]
+  /// Qualified names of the columns referenced by the expression, in alphabetical order
+  pub fn column_refs(&self) -> Result<Vec<String>> {
+    let mut columns = self
+      .value()
+      .to_columns()
+      .map_err(anyhow::Error::from)?
+      .iter()
+      .map(|column| column.flat_name())
+      .collect::<Vec<_>>();
+    columns.sort();
+    Ok(columns)
+  }
+
+  #[napi]
+  /// Resolve the output field of the expression against the schema of `df`,
+  /// which is named after the expression like in the results of `df.select([expr])`
+  ///
+  /// ```
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// col('a').add(lit(1.5)).getType(df);
+  /// // { name: 'a + Float64(1.5)', dataType: { typeId: TypeId.Float64 }, nullable: true }
+  /// ```
+  pub fn get_type(&self, df: &DataFrame) -> Result<Field> {
+    self.output_field(df.inner.schema())
+  }
+
+  #[napi]
+  /// Resolve the output field of the expression against `schema`, see `getType`
+  pub fn get_type_with_schema(&self, schema: Vec<Field>) -> Result<Field> {
+    self.output_field(&to_df_schema(&schema)?)
   }
 
   #[napi]
@@ -198,6 +472,23 @@ impl Expr {
       unreachable!("Expr must have a value");
     }
   }
+
+  /// The output field of the expression resolved against `schema`, named after the expression.
+  fn output_field(&self, schema: &DFSchema) -> Result<Field> {
+    let field = self.value().to_field(schema).map_err(anyhow::Error::from)?;
+    Ok(Field::try_from(field.field().as_ref())?)
+  }
+
+  /// Apply `f` to a copy of this expression and wrap the result into a new `Expr`,
+  /// leaving this one untouched.
+  fn transform(
+    &self,
+    f: impl FnOnce(datafusion::prelude::Expr) -> datafusion::prelude::Expr,
+  ) -> Expr {
+    Expr {
+      inner: Some(f(self.value())),
+    }
+  }
 }
 
 #[napi]
@@ -213,6 +504,189 @@ pub fn col(name: String) -> Expr {
   }
 }
 
+#[napi]
+/// Create a reference to the column `name` of the outer query, to correlate a subquery with it.
+/// `dataType` is the type of the column.
+///
+/// example:
+/// ```
+/// const orders = (await ctx.sql('SELECT * FROM orders')).filter(
+///   col('orders.customer_id').eq(outRefCol('customers.id', { typeId: TypeId.Int64 })),
+/// );
+/// const customers = (await ctx.sql('SELECT * FROM customers')).filter(exists(orders));
+/// ```
+pub fn out_ref_col(name: String, data_type: JsDataType) -> Result<Expr> {
+  Ok(Expr {
+    inner: Some(datafusion::logical_expr::expr_fn::out_ref_col(
+      DataType::try_from(&data_type)?,
+      &name,
+    )),
+  })
+}
+
+#[napi(ts_args_type = "value: string | number | bigint | boolean | Date | Buffer | null")]
+/// Create a literal expression, the type of the literal is decided by the JavaScript value:
+///
+/// - `null` and `undefined` => `Null`
+/// - `boolean` => `Boolean`
+/// - integral `number` => `Int64`, other `number` => `Float64`
+/// - `BigInt` => `Int64`, or `UInt64` if it exceeds `Int64`
+/// - `string` => `Utf8`
+/// - `Date` => `Timestamp` in milliseconds without timezone
+/// - `Buffer` => `Binary`
+///
+/// Use `litTyped` or the typed constructors like `litInt32` for the other types.
+///
+/// ```
+/// const expr = col('a').ltEq(lit(5));
+/// ```
+pub fn lit(value: JsUnknown) -> Result<Expr> {
+  Ok(scalar(to_scalar_value(value)?))
+}
+
+#[napi]
+/// Create an `Int32` literal expression
+pub fn lit_int32(value: i32) -> Expr {
+  scalar(ScalarValue::Int32(Some(value)))
+}
+
+#[napi]
+/// Create a `Float64` literal expression
+pub fn lit_float64(value: f64) -> Expr {
+  scalar(ScalarValue::Float64(Some(value)))
+}
+
+#[napi]
+/// Create a `Decimal128` literal expression from its string representation,
+/// the value must fit in `precision` digits with `scale` digits after the decimal point.
+///
+/// ```
+/// const price = litDecimal128('12.99', 10, 2);
+/// ```
+pub fn lit_decimal128(value: String, precision: u32, scale: i32) -> Result<Expr> {
+  let data_type = DataType::Decimal128(
+    precision.try_into().map_err(anyhow::Error::from)?,
+    scale.try_into().map_err(anyhow::Error::from)?,
+  );
+  let value = ScalarValue::try_from_string(value, &data_type).map_err(anyhow::Error::from)?;
+  Ok(scalar(value))
+}
+
+#[napi]
+/// Create a `Timestamp` literal expression, `value` is the elapsed time since UNIX epoch in `unit`,
+/// which defaults to `Millisecond`.
+///
+/// ```
+/// const since = litTimestamp(Date.parse('2023-01-01'), TimeUnit.Millisecond, '+00:00');
+/// ```
+pub fn lit_timestamp(value: i64, unit: Option<TimeUnit>, timezone: Option<String>) -> Expr {
+  let (value, timezone) = (Some(value), timezone.map(Into::into));
+  scalar(match unit.unwrap_or(TimeUnit::Millisecond) {
+    TimeUnit::Second => ScalarValue::TimestampSecond(value, timezone),
+    TimeUnit::Millisecond => ScalarValue::TimestampMillisecond(value, timezone),
+    TimeUnit::Microsecond => ScalarValue::TimestampMicrosecond(value, timezone),
+    TimeUnit::Nanosecond => ScalarValue::TimestampNanosecond(value, timezone),
+  })
+}
+
+#[napi]
+/// Create a `Date32` literal expression, `days` is the elapsed days since UNIX epoch
+pub fn lit_date32(days: i32) -> Expr {
+  scalar(ScalarValue::Date32(Some(days)))
+}
+
+#[napi]
+/// Create an `Interval` literal expression of `months`, `days` and `nanoseconds`,
+/// which are kept separately because their lengths vary.
+///
+/// ```
+/// const expr = binaryExpr(col('created_at'), Operator.Plus, litInterval(1, 15, 0)); // 1 month and 15 days later
+/// ```
+pub fn lit_interval(months: i32, days: i32, nanoseconds: i64) -> Expr {
+  scalar(ScalarValue::IntervalMonthDayNano(Some(
+    IntervalMonthDayNanoType::make_value(months, days, nanoseconds),
+  )))
+}
+
+#[napi]
+/// Create an `Interval` literal expression from the SQL interval syntax, which can be added to
+/// or subtracted from timestamps and dates.
+///
+/// ```
+/// const expr = col('created_at').add(interval('1 day 2 hours'));
+/// ```
+pub fn interval(value: String) -> Result<Expr> {
+  let value =
+    datafusion::common::parsers::parse_interval("month", &value).map_err(anyhow::Error::from)?;
+  Ok(scalar(value))
+}
+
+#[napi(
+  ts_args_type = "value: string | number | bigint | boolean | Date | Buffer | null, dataType: DataType"
+)]
+/// Create a literal expression of `dataType`, `value` is converted like [`lit`] and then cast
+/// to `dataType`, which fails if the value does not fit in it.
+///
+/// ```
+/// const small = litTyped(1, { typeId: TypeId.Int8 });
+/// const unsigned = litTyped(2n ** 63n, { typeId: TypeId.UInt64 });
+/// const day = litTyped(new Date('2023-01-01'), { typeId: TypeId.Date64 });
+/// ```
+pub fn lit_typed(value: JsUnknown, data_type: JsDataType) -> Result<Expr> {
+  let data_type = DataType::try_from(&data_type)?;
+  let value = to_scalar_value(value)?
+    .cast_to(&data_type)
+    .map_err(anyhow::Error::from)?;
+  Ok(scalar(value))
+}
+
+fn scalar(value: ScalarValue) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::Expr::Literal(value)),
+  }
+}
+
+fn to_scalar_value(value: JsUnknown) -> Result<ScalarValue> {
+  Ok(match value.get_type()? {
+    ValueType::Undefined | ValueType::Null => ScalarValue::Null,
+    ValueType::Boolean => ScalarValue::Boolean(Some(value.coerce_to_bool()?.get_value()?)),
+    ValueType::Number => {
+      let value = value.coerce_to_number()?.get_double()?;
+      if value.fract() == 0.0 && value >= i64::MIN as f64 && value < i64::MAX as f64 {
+        ScalarValue::Int64(Some(value as i64))
+      } else {
+        ScalarValue::Float64(Some(value))
+      }
+    }
+    ValueType::BigInt => {
+      let value = unsafe { value.cast::<JsBigInt>() };
+      match value.get_i64()? {
+        (value, true) => ScalarValue::Int64(Some(value)),
+        _ => match value.get_u64()? {
+          (value, true) => ScalarValue::UInt64(Some(value)),
+          _ => return Err(anyhow::anyhow!("BigInt literal exceeds 64 bits").into()),
+        },
+      }
+    }
+    ValueType::String => {
+      ScalarValue::Utf8(Some(value.coerce_to_string()?.into_utf8()?.into_owned()?))
+    }
+    _ if value.is_date()? => {
+      let time = unsafe { value.cast::<JsDate>() }.value_of()?;
+      if time.is_nan() {
+        return Err(anyhow::anyhow!("Invalid Date literal").into());
+      }
+      ScalarValue::TimestampMillisecond(Some(time as i64), None)
+    }
+    _ if value.is_buffer()? => ScalarValue::Binary(Some(
+      unsafe { value.cast::<JsBuffer>() }.into_value()?.to_vec(),
+    )),
+    value_type => {
+      return Err(anyhow::anyhow!("Unsupported literal of type `{value_type:?}`").into());
+    }
+  })
+}
+
 #[napi]
 /// Return a new expression `left <op> right`
 pub fn binary_expr(left: &Expr, op: Operator, right: &Expr) -> Expr {
@@ -301,6 +775,90 @@ pub fn in_list(expr: &Expr, list: Vec<&Expr>, negated: bool) -> Expr {
   }
 }
 
+#[napi]
+/// Create an `EXISTS (<subquery>)` expression
+pub fn exists(subquery: &DataFrame) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::exists(subquery_plan(subquery))),
+  }
+}
+
+#[napi]
+/// Create a `NOT EXISTS (<subquery>)` expression
+pub fn not_exists(subquery: &DataFrame) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::not_exists(subquery_plan(subquery))),
+  }
+}
+
+#[napi]
+/// Create a scalar subquery expression, `subquery` must return a single column and at most one row
+pub fn scalar_subquery(subquery: &DataFrame) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::scalar_subquery(subquery_plan(
+      subquery,
+    ))),
+  }
+}
+
+fn subquery_plan(df: &DataFrame) -> std::sync::Arc<datafusion::logical_expr::LogicalPlan> {
+  std::sync::Arc::new(df.value().into_unoptimized_plan())
+}
+
+/// Create a DataFusion schema of unqualified columns from the `fields`.
+fn to_df_schema(fields: &[Field]) -> anyhow::Result<DFSchema> {
+  Ok(DFSchema::try_from(to_arrow_schema(fields)?)?)
+}
+
+/// Plan the SQL expression `sql` against `schema`, only the built-in functions are available.
+fn parse_sql_expr(sql: &str, schema: &DFSchema) -> anyhow::Result<datafusion::prelude::Expr> {
+  let dialect = GenericDialect {};
+  let mut parser = Parser::new(&dialect).try_with_sql(sql)?;
+  let sql_expr = parser.parse_expr()?;
+  // `parse_expr` stops after the first complete expression, the rest of the input must not be ignored
+  let next = parser.peek_token().token;
+  if next != Token::EOF {
+    return Err(anyhow::anyhow!(
+      "Unexpected `{next}` after the expression `{sql_expr}`"
+    ));
+  }
+  let provider = ExprContextProvider::default();
+  Ok(SqlToRel::new(&provider).sql_to_expr(sql_expr, schema, &mut PlannerContext::new())?)
+}
+
+/// Planning context of the parsed SQL expressions, which can not reference tables.
+#[derive(Default)]
+struct ExprContextProvider {
+  options: ConfigOptions,
+}
+
+impl ContextProvider for ExprContextProvider {
+  fn get_table_provider(
+    &self,
+    name: TableReference,
+  ) -> datafusion::error::Result<std::sync::Arc<dyn TableSource>> {
+    Err(datafusion::error::DataFusionError::Plan(format!(
+      "Table `{name}` can not be referenced in an expression"
+    )))
+  }
+
+  fn get_function_meta(&self, _name: &str) -> Option<std::sync::Arc<ScalarUDF>> {
+    None
+  }
+
+  fn get_aggregate_meta(&self, _name: &str) -> Option<std::sync::Arc<AggregateUDF>> {
+    None
+  }
+
+  fn get_variable_type(&self, _variable_names: &[String]) -> Option<DataType> {
+    None
+  }
+
+  fn options(&self) -> &ConfigOptions {
+    &self.options
+  }
+}
+
 #[napi]
 /// Concatenates the text representations of all the arguments. NULL arguments are ignored.
 pub fn concat(args: Vec<&Expr>) -> Expr {
@@ -336,49 +894,586 @@ pub fn random() -> Expr {
   }
 }
 
-#[napi]
-/// Returns the approximate number of distinct input values.
-/// This function provides an approximation of count(DISTINCT x).
-/// Zero is returned if all input values are null.
-/// This function should produce a standard error of 0.81%,
-/// which is the standard deviation of the (approximately normal)
-/// error distribution over all possible sets.
-/// It does not guarantee an upper bound on the error for any specific input set.
-pub fn approx_distinct(expr: &Expr) -> Expr {
+/// Call the built-in scalar function `fun` with `args`, for the functions without a typed builder in DataFusion.
+fn scalar_function(fun: BuiltinScalarFunction, args: Vec<datafusion::prelude::Expr>) -> Expr {
   Expr {
-    inner: Some(datafusion::prelude::approx_distinct(expr.value())),
+    inner: Some(datafusion::prelude::Expr::ScalarFunction(
+      ScalarFunction::new(fun, args),
+    )),
   }
 }
 
+/// Append the optional trailing arguments which are provided.
+fn with_optional(
+  mut args: Vec<datafusion::prelude::Expr>,
+  optional: &[Option<&Expr>],
+) -> Vec<datafusion::prelude::Expr> {
+  args.extend(optional.iter().map_while(|e| e.map(|e| e.value())));
+  args
+}
+
 #[napi]
-/// Calculate an approximation of the median for `expr`.
-pub fn approx_median(expr: &Expr) -> Expr {
+/// Absolute value
+pub fn abs(expr: &Expr) -> Expr {
   Expr {
-    inner: Some(datafusion::prelude::approx_median(expr.value())),
+    inner: Some(datafusion::prelude::abs(expr.value())),
   }
 }
 
 #[napi]
-/// Calculate an approximation of the specified `percentile` for `expr`.
-pub fn approx_percentile_cont(expr: &Expr, percentile: &Expr) -> Expr {
+/// Nearest integer greater than or equal to `expr`
+pub fn ceil(expr: &Expr) -> Expr {
   Expr {
-    inner: Some(datafusion::prelude::approx_percentile_cont(
-      expr.value(),
-      percentile.value(),
-    )),
+    inner: Some(datafusion::prelude::ceil(expr.value())),
   }
 }
 
 #[napi]
-/// Calculate an approximation of the specified `percentile` for `expr` and `weight_expr`.
-pub fn approx_percentile_cont_with_weight(
-  expr: &Expr,
-  weight_expr: &Expr,
-  percentile: &Expr,
-) -> Expr {
+/// Nearest integer less than or equal to `expr`
+pub fn floor(expr: &Expr) -> Expr {
   Expr {
-    inner: Some(datafusion::prelude::approx_percentile_cont_with_weight(
-      expr.value(),
+    inner: Some(datafusion::prelude::floor(expr.value())),
+  }
+}
+
+#[napi]
+/// Round `expr` to `decimalPlaces` digits after the decimal point, which defaults to `0`
+pub fn round(expr: &Expr, decimal_places: Option<&Expr>) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::round(with_optional(
+      vec![expr.value()],
+      &[decimal_places],
+    ))),
+  }
+}
+
+#[napi]
+/// Truncate `expr` toward zero
+pub fn trunc(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::trunc(expr.value())),
+  }
+}
+
+#[napi]
+/// Sign of `expr`, `-1`, `0` or `1`
+pub fn signum(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::signum(expr.value())),
+  }
+}
+
+#[napi]
+/// Square root
+pub fn sqrt(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::sqrt(expr.value())),
+  }
+}
+
+#[napi]
+/// Exponential, `e ^ expr`
+pub fn exp(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::exp(expr.value())),
+  }
+}
+
+#[napi]
+/// Natural logarithm
+pub fn ln(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::ln(expr.value())),
+  }
+}
+
+#[napi]
+/// Base 2 logarithm
+pub fn log2(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::log2(expr.value())),
+  }
+}
+
+#[napi]
+/// Base 10 logarithm
+pub fn log10(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::log10(expr.value())),
+  }
+}
+
+#[napi]
+/// Return `base ^ exponent`
+pub fn power(base: &Expr, exponent: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::power(base.value(), exponent.value())),
+  }
+}
+
+#[napi]
+/// Sine, `expr` in radians
+pub fn sin(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::sin(expr.value())),
+  }
+}
+
+#[napi]
+/// Cosine, `expr` in radians
+pub fn cos(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::cos(expr.value())),
+  }
+}
+
+#[napi]
+/// Tangent, `expr` in radians
+pub fn tan(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::tan(expr.value())),
+  }
+}
+
+#[napi]
+/// Inverse sine in radians
+pub fn asin(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::asin(expr.value())),
+  }
+}
+
+#[napi]
+/// Inverse cosine in radians
+pub fn acos(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::acos(expr.value())),
+  }
+}
+
+#[napi]
+/// Inverse tangent in radians
+pub fn atan(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::atan(expr.value())),
+  }
+}
+
+#[napi]
+/// Inverse tangent of `y / x` in radians, using the signs of both to determine the quadrant
+pub fn atan2(y: &Expr, x: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::atan2(y.value(), x.value())),
+  }
+}
+
+#[napi]
+/// Convert the string to lower case
+pub fn lower(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::lower(expr.value())),
+  }
+}
+
+#[napi]
+/// Convert the string to upper case
+pub fn upper(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::upper(expr.value())),
+  }
+}
+
+#[napi]
+/// Remove the spaces from both sides of the string
+pub fn trim(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::trim(expr.value())),
+  }
+}
+
+#[napi]
+/// Remove the spaces from the left side of the string
+pub fn ltrim(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::ltrim(vec![expr.value()])),
+  }
+}
+
+#[napi]
+/// Remove the spaces from the right side of the string
+pub fn rtrim(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::rtrim(vec![expr.value()])),
+  }
+}
+
+#[napi]
+/// Extract `length` characters of the string from the `start` position, counting from 1,
+/// or the rest of the string if `length` is not provided
+pub fn substr(expr: &Expr, start: &Expr, length: Option<&Expr>) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::Substr,
+    with_optional(vec![expr.value(), start.value()], &[length]),
+  )
+}
+
+#[napi]
+/// Replace all the occurrences of `from` in the string with `to`
+pub fn replace(expr: &Expr, from: &Expr, to: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::replace(
+      expr.value(),
+      from.value(),
+      to.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Split the string on `delimiter` and return the `n`th part, counting from 1
+pub fn split_part(expr: &Expr, delimiter: &Expr, n: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::split_part(
+      expr.value(),
+      delimiter.value(),
+      n.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Whether the string starts with `prefix`
+pub fn starts_with(expr: &Expr, prefix: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::starts_with(
+      expr.value(),
+      prefix.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Number of characters in the string
+pub fn length(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::character_length(expr.value())),
+  }
+}
+
+#[napi]
+/// Pad the left side of the string with `fill`, which defaults to a space, to `length` characters.
+/// The string is truncated if it's longer than `length`.
+pub fn lpad(expr: &Expr, length: &Expr, fill: Option<&Expr>) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::lpad(with_optional(
+      vec![expr.value(), length.value()],
+      &[fill],
+    ))),
+  }
+}
+
+#[napi]
+/// Pad the right side of the string with `fill`, which defaults to a space, to `length` characters.
+/// The string is truncated if it's longer than `length`.
+pub fn rpad(expr: &Expr, length: &Expr, fill: Option<&Expr>) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::rpad(with_optional(
+      vec![expr.value(), length.value()],
+      &[fill],
+    ))),
+  }
+}
+
+#[napi]
+/// MD5 digest of the string, in hexadecimal
+pub fn md5(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::md5(expr.value())),
+  }
+}
+
+#[napi]
+/// SHA-256 digest of the string or binary
+pub fn sha256(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::sha256(expr.value())),
+  }
+}
+
+#[napi]
+/// Match the string against the regular expression `pattern`, returning the `List` of the captured groups
+/// of the first match, or `NULL` if there is no match. `flags` like `'i'` modify the matching.
+///
+/// ```
+/// const year = regexpMatch(col('date'), lit('(\\d{4})-\\d{2}-\\d{2}'));
+/// ```
+pub fn regexp_match(expr: &Expr, pattern: &Expr, flags: Option<&Expr>) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::regexp_match(with_optional(
+      vec![expr.value(), pattern.value()],
+      &[flags],
+    ))),
+  }
+}
+
+#[napi]
+/// Replace the substrings of the string matching the regular expression `pattern` with `replacement`,
+/// which can refer to the captured groups like `$1`. Only the first match is replaced unless `flags` contains `'g'`.
+///
+/// ```
+/// const masked = regexpReplace(col('phone'), lit('\\d'), lit('*'), lit('g'));
+/// ```
+pub fn regexp_replace(
+  expr: &Expr,
+  pattern: &Expr,
+  replacement: &Expr,
+  flags: Option<&Expr>,
+) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::regexp_replace(with_optional(
+      vec![expr.value(), pattern.value(), replacement.value()],
+      &[flags],
+    ))),
+  }
+}
+
+#[napi]
+/// Current timestamp in UTC, which is the same for all the rows of a query
+pub fn now() -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::now()),
+  }
+}
+
+#[napi]
+/// Current date in UTC, which is the same for all the rows of a query
+pub fn current_date() -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::current_date()),
+  }
+}
+
+#[napi]
+/// Current time in UTC, which is the same for all the rows of a query
+pub fn current_time() -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::current_time()),
+  }
+}
+
+#[napi]
+/// Truncate the timestamp to `granularity`, one of `'year'`, `'quarter'`, `'month'`, `'week'`, `'day'`,
+/// `'hour'`, `'minute'`, `'second'`, `'millisecond'` and `'microsecond'`
+///
+/// ```
+/// const hour = dateTrunc('hour', col('created_at'));
+/// ```
+pub fn date_trunc(granularity: String, expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::date_trunc(
+      datafusion::prelude::lit(granularity),
+      expr.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Extract `part` of the timestamp or date as a `Float64`, like `'year'`, `'month'`, `'day'`, `'dow'`,
+/// `'hour'` or `'epoch'`
+pub fn date_part(part: String, expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::date_part(
+      datafusion::prelude::lit(part),
+      expr.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Bin the timestamp into intervals of `stride` starting from `origin`, which defaults to UNIX epoch.
+/// Unlike `dateTrunc`, `stride` can be any interval.
+///
+/// ```
+/// const bucket = dateBin(interval('15 minutes'), col('created_at'));
+/// ```
+pub fn date_bin(stride: &Expr, expr: &Expr, origin: Option<&Expr>) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::DateBin,
+    with_optional(vec![stride.value(), expr.value()], &[origin]),
+  )
+}
+
+#[napi]
+/// Convert the string or the integer of nanoseconds since UNIX epoch into a `Timestamp` in nanoseconds
+pub fn to_timestamp(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::to_timestamp(expr.value())),
+  }
+}
+
+#[napi]
+/// Convert the string or the integer of milliseconds since UNIX epoch into a `Timestamp` in milliseconds
+pub fn to_timestamp_millis(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::to_timestamp_millis(expr.value())),
+  }
+}
+
+#[napi]
+/// Convert the string or the integer of microseconds since UNIX epoch into a `Timestamp` in microseconds
+pub fn to_timestamp_micros(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::to_timestamp_micros(expr.value())),
+  }
+}
+
+#[napi]
+/// Convert the string or the integer of seconds since UNIX epoch into a `Timestamp` in seconds
+pub fn to_timestamp_seconds(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::to_timestamp_seconds(expr.value())),
+  }
+}
+
+#[napi]
+/// Convert the integer of seconds since UNIX epoch into a `Timestamp` in seconds
+pub fn from_unixtime(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::from_unixtime(expr.value())),
+  }
+}
+
+#[napi]
+/// Create a `List` of the values of `args`, which are coerced into a common type
+pub fn make_array(args: Vec<&Expr>) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::MakeArray,
+    args.iter().map(|e| e.value()).collect(),
+  )
+}
+
+#[napi]
+/// Append `element` to the end of the list
+pub fn array_append(array: &Expr, element: &Expr) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::ArrayAppend,
+    vec![array.value(), element.value()],
+  )
+}
+
+#[napi]
+/// Concatenate the lists
+pub fn array_concat(arrays: Vec<&Expr>) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::ArrayConcat,
+    arrays.iter().map(|e| e.value()).collect(),
+  )
+}
+
+#[napi]
+/// Number of elements in the list
+pub fn array_length(array: &Expr) -> Expr {
+  scalar_function(BuiltinScalarFunction::ArrayLength, vec![array.value()])
+}
+
+#[napi]
+/// Whether the list contains `element`, `NULL` if the list is `NULL`
+pub fn array_contains(array: &Expr, element: &Expr) -> Expr {
+  let position = scalar_function(
+    BuiltinScalarFunction::ArrayPosition,
+    vec![array.value(), element.value()],
+  );
+  Expr {
+    inner: Some(datafusion::prelude::Expr::Case(
+      datafusion::logical_expr::Case::new(
+        None,
+        vec![(
+          Box::new(array.value().is_null()),
+          Box::new(datafusion::prelude::lit(ScalarValue::Boolean(None))),
+        )],
+        Some(Box::new(position.value().is_not_null())),
+      ),
+    )),
+  }
+}
+
+#[napi]
+/// Element of the list at `index`, counting from 1, or `NULL` if `index` is out of bounds
+///
+/// ```
+/// const first = arrayElement(col('tags'), 1);
+/// ```
+pub fn array_element(array: &Expr, index: i64) -> Expr {
+  Expr {
+    inner: Some(get_indexed_field(
+      array.value(),
+      ScalarValue::Int64(Some(index)),
+    )),
+  }
+}
+
+#[napi(js_name = "struct")]
+/// Create a `Struct` of the values of `args`, with the fields named `c0`, `c1`, ...
+pub fn struct_expr(args: Vec<&Expr>) -> Expr {
+  scalar_function(
+    BuiltinScalarFunction::Struct,
+    args.iter().map(|e| e.value()).collect(),
+  )
+}
+
+fn get_indexed_field(
+  expr: datafusion::prelude::Expr,
+  key: ScalarValue,
+) -> datafusion::prelude::Expr {
+  datafusion::prelude::Expr::GetIndexedField(datafusion::logical_expr::GetIndexedField::new(
+    Box::new(expr),
+    key,
+  ))
+}
+
+#[napi]
+/// Returns the approximate number of distinct input values.
+/// This function provides an approximation of count(DISTINCT x).
+/// Zero is returned if all input values are null.
+/// This function should produce a standard error of 0.81%,
+/// which is the standard deviation of the (approximately normal)
+/// error distribution over all possible sets.
+/// It does not guarantee an upper bound on the error for any specific input set.
+pub fn approx_distinct(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::approx_distinct(expr.value())),
+  }
+}
+
+#[napi]
+/// Calculate an approximation of the median for `expr`.
+pub fn approx_median(expr: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::approx_median(expr.value())),
+  }
+}
+
+#[napi]
+/// Calculate an approximation of the specified `percentile` for `expr`.
+pub fn approx_percentile_cont(expr: &Expr, percentile: &Expr) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::approx_percentile_cont(
+      expr.value(),
+      percentile.value(),
+    )),
+  }
+}
+
+#[napi]
+/// Calculate an approximation of the specified `percentile` for `expr` and `weight_expr`.
+pub fn approx_percentile_cont_with_weight(
+  expr: &Expr,
+  weight_expr: &Expr,
+  percentile: &Expr,
+) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::approx_percentile_cont_with_weight(
+      expr.value(),
       weight_expr.value(),
       percentile.value(),
     )),
@@ -474,6 +1569,407 @@ pub fn is_not_unknown(expr: &Expr) -> Expr {
   }
 }
 
+#[napi]
+/// Builder of `CASE` expressions, created by `when` or `caseExpr`
+pub struct CaseBuilder {
+  inner: datafusion::logical_expr::conditional_expressions::CaseBuilder,
+}
+
+#[napi]
+impl CaseBuilder {
+  #[napi]
+  /// Add a `WHEN when THEN then` branch
+  pub fn when(&mut self, when: &Expr, then: &Expr) -> &Self {
+    self.inner.when(when.value(), then.value());
+    self
+  }
+
+  #[napi]
+  /// Finish the `CASE` expression with `ELSE value`
+  pub fn otherwise(&mut self, value: &Expr) -> Result<Expr> {
+    Ok(Expr {
+      inner: Some(
+        self
+          .inner
+          .otherwise(value.value())
+          .map_err(anyhow::Error::from)?,
+      ),
+    })
+  }
+
+  #[napi]
+  /// Finish the `CASE` expression without `ELSE`, which results in `NULL` if no branch matches
+  pub fn end(&self) -> Result<Expr> {
+    Ok(Expr {
+      inner: Some(self.inner.end().map_err(anyhow::Error::from)?),
+    })
+  }
+}
+
+#[napi]
+/// Create a `CASE WHEN when THEN then ...` expression builder with the conditions of the branches
+///
+/// ```
+/// const bucket = when(col('age').lt(lit(18)), lit('minor'))
+///   .when(col('age').lt(lit(65)), lit('adult'))
+///   .otherwise(lit('senior'));
+/// ```
+pub fn when(when: &Expr, then: &Expr) -> CaseBuilder {
+  CaseBuilder {
+    inner: datafusion::logical_expr::when(when.value(), then.value()),
+  }
+}
+
+#[napi]
+/// Create a `CASE base WHEN value THEN then ...` expression builder comparing `base` with the values of the branches
+///
+/// ```
+/// const label = caseExpr(col('status'))
+///   .when(lit(1), lit('active'))
+///   .when(lit(2), lit('suspended'))
+///   .end();
+/// ```
+pub fn case_expr(base: &Expr) -> CaseBuilder {
+  CaseBuilder {
+    inner: datafusion::logical_expr::case(base.value()),
+  }
+}
+
+#[napi(string_enum)]
+/// Units of the bounds of a `WindowFrame`
+pub enum WindowFrameUnits {
+  /// Offsets are numbers of rows
+  #[napi(value = "rows")]
+  Rows,
+  /// Offsets are differences of the value of the single `orderBy` expression
+  #[napi(value = "range")]
+  Range,
+  /// Offsets are numbers of groups of peer rows, which have the same `orderBy` values
+  #[napi(value = "groups")]
+  Groups,
+}
+
+#[napi(object)]
+/// The frame of a window expression, which is the set of rows in the partition used to compute
+/// the value of the current row.
+///
+/// Bounds are offsets from the current row, negative for `PRECEDING`, positive for `FOLLOWING`,
+/// `0` for `CURRENT ROW`, `-Infinity` and `Infinity` for `UNBOUNDED PRECEDING` and `UNBOUNDED FOLLOWING`.
+pub struct WindowFrame {
+  pub units: WindowFrameUnits,
+  /// Defaults to `-Infinity`
+  pub start: Option<f64>,
+  /// Defaults to `0`
+  pub end: Option<f64>,
+}
+
+impl From<WindowFrame> for datafusion::logical_expr::WindowFrame {
+  fn from(value: WindowFrame) -> Self {
+    use datafusion::logical_expr::{WindowFrameBound, WindowFrameUnits as Units};
+
+    let units = match value.units {
+      WindowFrameUnits::Rows => Units::Rows,
+      WindowFrameUnits::Range => Units::Range,
+      WindowFrameUnits::Groups => Units::Groups,
+    };
+    let bound = |offset: f64| {
+      let scalar = |offset: f64| {
+        if offset.is_infinite() {
+          ScalarValue::UInt64(None)
+        } else if units != Units::Range {
+          ScalarValue::UInt64(Some(offset as u64))
+        } else if offset.fract() == 0.0 {
+          ScalarValue::Int64(Some(offset as i64))
+        } else {
+          ScalarValue::Float64(Some(offset))
+        }
+      };
+      if offset < 0.0 {
+        WindowFrameBound::Preceding(scalar(-offset))
+      } else if offset > 0.0 {
+        WindowFrameBound::Following(scalar(offset))
+      } else {
+        WindowFrameBound::CurrentRow
+      }
+    };
+    let start_bound = bound(value.start.unwrap_or(f64::NEG_INFINITY));
+    let end_bound = bound(value.end.unwrap_or(0.0));
+    datafusion::logical_expr::WindowFrame {
+      units,
+      start_bound,
+      end_bound,
+    }
+  }
+}
+
+/// Read an optional `Array` of `Expr` instances from a JavaScript object property.
+fn to_exprs(env: &Env, values: Option<Vec<JsObject>>) -> Result<Vec<datafusion::prelude::Expr>> {
+  values
+    .into_iter()
+    .flatten()
+    .map(|value| Ok(unsafe { Expr::from_napi_ref(env.raw(), value.raw()) }?.value()))
+    .collect()
+}
+
+fn window_function(
+  fun: datafusion::logical_expr::BuiltInWindowFunction,
+  args: Vec<datafusion::prelude::Expr>,
+) -> Expr {
+  Expr {
+    inner: Some(datafusion::prelude::Expr::WindowFunction(
+      datafusion::logical_expr::expr::WindowFunction::new(
+        datafusion::logical_expr::WindowFunction::BuiltInWindowFunction(fun),
+        args,
+        vec![],
+        vec![],
+        datafusion::logical_expr::WindowFrame::new(false),
+      ),
+    )),
+  }
+}
+
+#[napi]
+/// Create a window function returning the number of the current row within its partition, counting from 1
+pub fn row_number() -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::RowNumber,
+    vec![],
+  )
+}
+
+#[napi]
+/// Create a window function returning the rank of the current row with gaps,
+/// which is the `rowNumber` of its first peer row
+pub fn rank() -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::Rank,
+    vec![],
+  )
+}
+
+#[napi]
+/// Create a window function returning the rank of the current row without gaps,
+/// which counts the groups of peer rows
+pub fn dense_rank() -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::DenseRank,
+    vec![],
+  )
+}
+
+#[napi]
+/// Create a window function returning the relative rank of the current row, `(rank - 1) / (rows - 1)`
+pub fn percent_rank() -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::PercentRank,
+    vec![],
+  )
+}
+
+#[napi]
+/// Create a window function returning the cumulative distribution of the current row,
+/// `(number of rows preceding or peer with the current row) / rows`
+pub fn cume_dist() -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::CumeDist,
+    vec![],
+  )
+}
+
+#[napi]
+/// Create a window function dividing the partition into `buckets` groups as equal as possible,
+/// returning the group number of the current row, counting from 1
+pub fn ntile(buckets: i64) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::Ntile,
+    vec![datafusion::prelude::lit(buckets)],
+  )
+}
+
+#[napi]
+/// Create a window function returning `expr` evaluated at the row `offset` rows before the current row,
+/// or `defaultValue` if there is no such row. `offset` defaults to `1`, `defaultValue` defaults to `NULL`.
+pub fn lag(expr: &Expr, offset: Option<i64>, default_value: Option<&Expr>) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::Lag,
+    offset_args(expr, offset, default_value),
+  )
+}
+
+#[napi]
+/// Create a window function returning `expr` evaluated at the row `offset` rows after the current row,
+/// or `defaultValue` if there is no such row. `offset` defaults to `1`, `defaultValue` defaults to `NULL`.
+pub fn lead(expr: &Expr, offset: Option<i64>, default_value: Option<&Expr>) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::Lead,
+    offset_args(expr, offset, default_value),
+  )
+}
+
+fn offset_args(
+  expr: &Expr,
+  offset: Option<i64>,
+  default_value: Option<&Expr>,
+) -> Vec<datafusion::prelude::Expr> {
+  let mut args = vec![expr.value()];
+  if offset.is_some() || default_value.is_some() {
+    args.push(datafusion::prelude::lit(offset.unwrap_or(1)));
+  }
+  args.extend(default_value.map(|e| e.value()));
+  args
+}
+
+#[napi]
+/// Create a window function returning `expr` evaluated at the first row of the window frame
+pub fn first_value(expr: &Expr) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::FirstValue,
+    vec![expr.value()],
+  )
+}
+
+#[napi]
+/// Create a window function returning `expr` evaluated at the last row of the window frame
+pub fn last_value(expr: &Expr) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::LastValue,
+    vec![expr.value()],
+  )
+}
+
+#[napi]
+/// Create a window function returning `expr` evaluated at the `n`th row of the window frame, counting from 1
+pub fn nth_value(expr: &Expr, n: i64) -> Expr {
+  window_function(
+    datafusion::logical_expr::BuiltInWindowFunction::NthValue,
+    vec![expr.value(), datafusion::prelude::lit(n)],
+  )
+}
+
+#[napi(object)]
+/// A node of the tree of an expression, returned by `Expr.toJSON()`
+///
+/// ```
+/// col('a').gt(lit(1)).toJSON();
+/// // {
+/// //   kind: 'BinaryExpr',
+/// //   operator: '>',
+/// //   children: [
+/// //     { kind: 'Column', name: 'a', children: [] },
+/// //     { kind: 'Literal', value: '1', dataType: { typeId: TypeId.Int64 }, children: [] },
+/// //   ],
+/// // }
+/// ```
+pub struct ExprNode {
+  /// Name of the DataFusion expression variant, like `Column`, `Literal`, `BinaryExpr` or `ScalarFunction`
+  pub kind: String,
+  /// Name of the column, the alias or the function
+  pub name: Option<String>,
+  /// Table of the qualified column
+  pub relation: Option<String>,
+  /// Operator of `BinaryExpr`, like `+`, `=` or `AND`
+  pub operator: Option<String>,
+  /// Value of `Literal`, formatted as a string, `NULL` for null values
+  pub value: Option<String>,
+  /// Type of `Literal`, or the target type of `Cast` and `TryCast`, omitted if it is not supported by `DataType`
+  pub data_type: Option<JsDataType>,
+  /// Whether `Between`, `InList`, `Like`, `ILike`, `SimilarTo`, `Exists` or `InSubquery` is negated
+  pub negated: Option<bool>,
+  /// Escape character of `Like`, `ILike` and `SimilarTo`
+  pub escape_char: Option<String>,
+  /// Whether `Sort` is ascending
+  pub asc: Option<bool>,
+  /// Whether `Sort` puts nulls first
+  pub nulls_first: Option<bool>,
+  /// Sub-expressions, in the order of the operands or the arguments
+  pub children: Vec<ExprNode>,
+}
+
+impl TryFrom<&datafusion::prelude::Expr> for ExprNode {
+  type Error = datafusion::error::DataFusionError;
+
+  fn try_from(expr: &datafusion::prelude::Expr) -> std::result::Result<Self, Self::Error> {
+    use datafusion::common::tree_node::{TreeNode, VisitRecursion};
+    use datafusion::logical_expr::expr::{
+      AggregateFunction, AggregateUDF, Between, Exists, InList, InSubquery, Like, ScalarUDF, Sort,
+      WindowFunction,
+    };
+    use datafusion::logical_expr::{BinaryExpr, Cast, TryCast};
+    use datafusion::prelude::Expr as E;
+
+    let mut node = ExprNode {
+      kind: expr.variant_name().to_owned(),
+      name: None,
+      relation: None,
+      operator: None,
+      value: None,
+      data_type: None,
+      negated: None,
+      escape_char: None,
+      asc: None,
+      nulls_first: None,
+      children: vec![],
+    };
+    match expr {
+      E::Column(column) => {
+        node.name = Some(column.name.clone());
+        node.relation = column
+          .relation
+          .as_ref()
+          .map(|relation| relation.to_string());
+      }
+      E::Alias(_, name) => node.name = Some(name.clone()),
+      E::Literal(value) => {
+        node.value = Some(value.to_string());
+        node.data_type = JsDataType::try_from(&value.get_datatype()).ok();
+      }
+      E::BinaryExpr(BinaryExpr { op, .. }) => node.operator = Some(op.to_string()),
+      E::Cast(Cast { data_type, .. }) | E::TryCast(TryCast { data_type, .. }) => {
+        node.data_type = JsDataType::try_from(data_type).ok();
+      }
+      E::ScalarFunction(ScalarFunction { fun, .. }) => node.name = Some(fun.to_string()),
+      E::ScalarUDF(ScalarUDF { fun, .. }) => node.name = Some(fun.name.clone()),
+      E::AggregateFunction(AggregateFunction { fun, .. }) => node.name = Some(fun.to_string()),
+      E::AggregateUDF(AggregateUDF { fun, .. }) => node.name = Some(fun.name.clone()),
+      E::WindowFunction(WindowFunction { fun, .. }) => node.name = Some(fun.to_string()),
+      E::Between(Between { negated, .. })
+      | E::InList(InList { negated, .. })
+      | E::Exists(Exists { negated, .. })
+      | E::InSubquery(InSubquery { negated, .. }) => node.negated = Some(*negated),
+      E::Like(Like {
+        negated,
+        escape_char,
+        ..
+      })
+      | E::ILike(Like {
+        negated,
+        escape_char,
+        ..
+      })
+      | E::SimilarTo(Like {
+        negated,
+        escape_char,
+        ..
+      }) => {
+        node.negated = Some(*negated);
+        node.escape_char = escape_char.map(String::from);
+      }
+      E::Sort(Sort {
+        asc, nulls_first, ..
+      }) => {
+        node.asc = Some(*asc);
+        node.nulls_first = Some(*nulls_first);
+      }
+      _ => {}
+    }
+    expr.apply_children(&mut |child| {
+      node.children.push(ExprNode::try_from(child)?);
+      Ok(VisitRecursion::Continue)
+    })?;
+    Ok(node)
+  }
+}
+
 #[napi]
 /// Operators applied to expressions
 #[derive(PartialEq, Eq, PartialOrd, Hash)]
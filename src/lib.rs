@@ -10,4 +10,10 @@ static ALLOC: mimalloc_rust::GlobalMiMalloc = mimalloc_rust::GlobalMiMalloc;
 
 pub mod data_frame;
 pub mod expr;
+mod options;
+pub mod reader;
+pub mod record_batch;
+pub mod record_batch_stream;
+pub mod schema;
 pub mod session_context;
+pub mod writer;
@@ -0,0 +1,9 @@
+/// Convert an option which must be a single ASCII character, like the CSV delimiter, into a byte.
+pub(crate) fn to_byte(name: &str, value: &str) -> anyhow::Result<u8> {
+  match value.as_bytes() {
+    [byte] => Ok(*byte),
+    _ => Err(anyhow::anyhow!(
+      "`{name}` must be a single ASCII character, got `{value}`"
+    )),
+  }
+}
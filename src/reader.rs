@@ -0,0 +1,197 @@
+use datafusion::arrow::datatypes::Schema;
+use napi_derive::napi;
+
+use crate::options::to_byte;
+use crate::schema::{to_arrow_schema, Field};
+
+#[napi(string_enum)]
+/// Compression codec of the files to read
+pub enum FileCompressionType {
+  /// No compression
+  #[napi(value = "uncompressed")]
+  Uncompressed,
+  /// Gzip compression, usually with the `.gz` extension
+  #[napi(value = "gzip")]
+  Gzip,
+  /// Bzip2 compression, usually with the `.bz2` extension
+  #[napi(value = "bzip2")]
+  Bzip2,
+  /// Xz compression, usually with the `.xz` extension
+  #[napi(value = "xz")]
+  Xz,
+  /// Zstandard compression, usually with the `.zst` extension
+  #[napi(value = "zstd")]
+  Zstd,
+}
+
+impl From<FileCompressionType>
+  for datafusion::datasource::file_format::file_type::FileCompressionType
+{
+  fn from(value: FileCompressionType) -> Self {
+    use datafusion::datasource::file_format::file_type::FileCompressionType as Compression;
+
+    match value {
+      FileCompressionType::Uncompressed => Compression::UNCOMPRESSED,
+      FileCompressionType::Gzip => Compression::GZIP,
+      FileCompressionType::Bzip2 => Compression::BZIP2,
+      FileCompressionType::Xz => Compression::XZ,
+      FileCompressionType::Zstd => Compression::ZSTD,
+    }
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct ParquetReadOptions {
+  /// Only the files with this extension are read, defaults to `.parquet`
+  pub file_extension: Option<String>,
+  /// Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+  /// the `nullable` of the fields is ignored
+  pub table_partition_cols: Option<Vec<Field>>,
+  /// Skip the row groups and pages with the statistics of the Parquet metadata,
+  /// defaults to the `datafusion.execution.parquet.pruning` config
+  pub parquet_pruning: Option<bool>,
+  /// Ignore the metadata of the Parquet schema, which avoids conflicts between files with different metadata,
+  /// defaults to the `datafusion.execution.parquet.skip_metadata` config
+  pub skip_metadata: Option<bool>,
+}
+
+impl ParquetReadOptions {
+  /// Borrow these options as the DataFusion `ParquetReadOptions`.
+  pub(crate) fn to_options(&self) -> anyhow::Result<datafusion::prelude::ParquetReadOptions<'_>> {
+    let mut options = datafusion::prelude::ParquetReadOptions {
+      table_partition_cols: to_partition_cols(self.table_partition_cols.as_deref())?,
+      parquet_pruning: self.parquet_pruning,
+      skip_metadata: self.skip_metadata,
+      ..Default::default()
+    };
+    if let Some(file_extension) = &self.file_extension {
+      options.file_extension = file_extension;
+    }
+    Ok(options)
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct NdJsonReadOptions {
+  /// The schema of the files, inferred from the first `schemaInferMaxRecords` lines if not provided
+  pub schema: Option<Vec<Field>>,
+  /// Maximum number of lines to read for the schema inference, defaults to `1000`
+  pub schema_infer_max_records: Option<u32>,
+  /// Only the files with this extension are read, defaults to `.json`.
+  /// Compressed files usually have another extension, like `.json.gz`
+  pub file_extension: Option<String>,
+  /// Defaults to `FileCompressionType.Uncompressed`
+  pub file_compression_type: Option<FileCompressionType>,
+  /// Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+  /// the `nullable` of the fields is ignored
+  pub table_partition_cols: Option<Vec<Field>>,
+}
+
+impl NdJsonReadOptions {
+  /// The explicit schema, which must outlive the options returned by [`NdJsonReadOptions::to_options`].
+  pub(crate) fn schema(&self) -> anyhow::Result<Option<Schema>> {
+    self.schema.as_deref().map(to_arrow_schema).transpose()
+  }
+
+  /// Borrow these options as the DataFusion `NdJsonReadOptions`.
+  pub(crate) fn to_options<'a>(
+    &'a self,
+    schema: Option<&'a Schema>,
+  ) -> anyhow::Result<datafusion::prelude::NdJsonReadOptions<'a>> {
+    let mut options = datafusion::prelude::NdJsonReadOptions {
+      schema,
+      table_partition_cols: to_partition_cols(self.table_partition_cols.as_deref())?,
+      ..Default::default()
+    };
+    if let Some(schema_infer_max_records) = self.schema_infer_max_records {
+      options.schema_infer_max_records = schema_infer_max_records as usize;
+    }
+    if let Some(file_extension) = &self.file_extension {
+      options.file_extension = file_extension;
+    }
+    if let Some(file_compression_type) = self.file_compression_type {
+      options.file_compression_type = file_compression_type.into();
+    }
+    Ok(options)
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct CsvReadOptions {
+  /// Whether the first line is a header line with the column names, defaults to `true`
+  pub has_header: Option<bool>,
+  /// Defaults to `,`
+  pub delimiter: Option<String>,
+  /// Defaults to `"`
+  pub quote: Option<String>,
+  /// The escape character in quoted values, quotes are escaped by doubling them if not provided
+  pub escape: Option<String>,
+  /// The schema of the files, inferred from the first `schemaInferMaxRecords` lines if not provided
+  pub schema: Option<Vec<Field>>,
+  /// Maximum number of lines to read for the schema inference, defaults to `1000`
+  pub schema_infer_max_records: Option<u32>,
+  /// Only the files with this extension are read, defaults to `.csv`.
+  /// Compressed files usually have another extension, like `.csv.gz`
+  pub file_extension: Option<String>,
+  /// Defaults to `FileCompressionType.Uncompressed`
+  pub file_compression_type: Option<FileCompressionType>,
+  /// Columns of the Hive-style partitioned directories, like `year=2023/month=01`,
+  /// the `nullable` of the fields is ignored
+  pub table_partition_cols: Option<Vec<Field>>,
+}
+
+impl CsvReadOptions {
+  /// The explicit schema, which must outlive the options returned by [`CsvReadOptions::to_options`].
+  pub(crate) fn schema(&self) -> anyhow::Result<Option<Schema>> {
+    self.schema.as_deref().map(to_arrow_schema).transpose()
+  }
+
+  /// Borrow these options as the DataFusion `CsvReadOptions`.
+  pub(crate) fn to_options<'a>(
+    &'a self,
+    schema: Option<&'a Schema>,
+  ) -> anyhow::Result<datafusion::prelude::CsvReadOptions<'a>> {
+    let mut options = datafusion::prelude::CsvReadOptions {
+      schema,
+      table_partition_cols: to_partition_cols(self.table_partition_cols.as_deref())?,
+      escape: self
+        .escape
+        .as_deref()
+        .map(|e| to_byte("escape", e))
+        .transpose()?,
+      ..Default::default()
+    };
+    if let Some(has_header) = self.has_header {
+      options.has_header = has_header;
+    }
+    if let Some(delimiter) = &self.delimiter {
+      options.delimiter = to_byte("delimiter", delimiter)?;
+    }
+    if let Some(quote) = &self.quote {
+      options.quote = to_byte("quote", quote)?;
+    }
+    if let Some(schema_infer_max_records) = self.schema_infer_max_records {
+      options.schema_infer_max_records = schema_infer_max_records as usize;
+    }
+    if let Some(file_extension) = &self.file_extension {
+      options.file_extension = file_extension;
+    }
+    if let Some(file_compression_type) = self.file_compression_type {
+      options.file_compression_type = file_compression_type.into();
+    }
+    Ok(options)
+  }
+}
+
+fn to_partition_cols(
+  fields: Option<&[Field]>,
+) -> anyhow::Result<Vec<(String, datafusion::arrow::datatypes::DataType)>> {
+  fields
+    .unwrap_or_default()
+    .iter()
+    .map(|field| Ok((field.name.clone(), (&field.data_type).try_into()?)))
+    .collect()
+}
@@ -0,0 +1,549 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use datafusion::arrow::array::{
+  as_boolean_array, as_generic_binary_array, as_largestring_array, as_primitive_array,
+  as_string_array, new_null_array, Array, ArrayRef, BooleanArray,
+  Float64Array as ArrowFloat64Array, Int64Array, NullArray, StringArray, TimestampMillisecondArray,
+};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{
+  DataType, Date32Type, Date64Type, Field, Float32Type, Float64Type, Int16Type, Int32Type,
+  Int64Type, Int8Type, Schema, SchemaRef, TimeUnit, TimestampMicrosecondType,
+  TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
+  UInt64Type, UInt8Type,
+};
+use datafusion::arrow::ipc::reader::{FileReader, StreamReader};
+use datafusion::arrow::ipc::writer::{FileWriter, StreamWriter};
+use datafusion::arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use datafusion::arrow::temporal_conversions::timestamp_ms_to_datetime;
+use datafusion::arrow::util::display::array_value_to_string;
+use napi::bindgen_prelude::*;
+use napi::{
+  Env, JsBigInt, JsDate, JsFunction, JsObject, JsString, JsUnknown, NapiRaw, NapiValue, ValueType,
+};
+use napi_derive::napi;
+
+#[napi(object)]
+#[derive(Default, Clone, Copy)]
+pub struct CollectOptions {
+  /// Convert `Int64` and `UInt64` values into `BigInt` instead of `number`.
+  ///
+  /// Defaults to `false`, values beyond `Number.MAX_SAFE_INTEGER` lose precision.
+  pub int64_as_big_int: Option<bool>,
+}
+
+#[napi(string_enum)]
+/// Arrow IPC format
+pub enum ArrowIpcFormat {
+  /// The streaming format, read it with `RecordBatchStreamReader` or `tableFromIPC`
+  #[napi(value = "stream")]
+  Stream,
+  /// The random access file format, read it with `RecordBatchFileReader` or `tableFromIPC`
+  #[napi(value = "file")]
+  File,
+}
+
+#[napi(object)]
+pub struct ArrowIpcOptions {
+  /// Defaults to `ArrowIpcFormat.Stream`
+  pub format: Option<ArrowIpcFormat>,
+}
+
+/// Rows of the collected [`RecordBatch`]es, converted into JavaScript objects
+/// keyed by column name when passed back to JavaScript.
+pub struct Rows {
+  pub(crate) batches: Vec<RecordBatch>,
+  pub(crate) options: CollectOptions,
+}
+
+impl ToNapiValue for Rows {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from_raw(env);
+    let rows = to_js_rows(&env, &val.batches, &val.options)?;
+    Ok(rows.raw())
+  }
+}
+
+/// Convert the [`RecordBatch`]es into an `Array` of row objects.
+pub(crate) fn to_js_rows(
+  env: &Env,
+  batches: &[RecordBatch],
+  options: &CollectOptions,
+) -> Result<JsObject> {
+  let len = batches.iter().map(|b| b.num_rows()).sum::<usize>();
+  let mut rows = env.create_array_with_length(len)?;
+  let mut index = 0;
+  for batch in batches {
+    let schema = batch.schema();
+    for row in 0..batch.num_rows() {
+      let mut obj = env.create_object()?;
+      for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        obj.set_named_property(
+          field.name(),
+          to_js_value(env, column.as_ref(), row, options)?,
+        )?;
+      }
+      rows.set_element(index, obj)?;
+      index += 1;
+    }
+  }
+  Ok(rows)
+}
+
+/// Convert the value at `index` of `array` into a JavaScript value,
+/// see [`crate::data_frame::DataFrame::collect`] for the type mapping.
+pub(crate) fn to_js_value(
+  env: &Env,
+  array: &dyn Array,
+  index: usize,
+  options: &CollectOptions,
+) -> Result<JsUnknown> {
+  if array.is_null(index) {
+    return to_unknown(env, Null);
+  }
+  let int64_as_big_int = options.int64_as_big_int.unwrap_or(false);
+  match array.data_type() {
+    DataType::Boolean => to_unknown(env, as_boolean_array(array).value(index)),
+    DataType::Int8 => to_unknown(
+      env,
+      as_primitive_array::<Int8Type>(array).value(index) as i32,
+    ),
+    DataType::Int16 => to_unknown(
+      env,
+      as_primitive_array::<Int16Type>(array).value(index) as i32,
+    ),
+    DataType::Int32 => to_unknown(env, as_primitive_array::<Int32Type>(array).value(index)),
+    DataType::Int64 => {
+      let value = as_primitive_array::<Int64Type>(array).value(index);
+      if int64_as_big_int {
+        to_unknown(env, BigInt::from(value))
+      } else {
+        to_unknown(env, value)
+      }
+    }
+    DataType::UInt8 => to_unknown(
+      env,
+      as_primitive_array::<UInt8Type>(array).value(index) as u32,
+    ),
+    DataType::UInt16 => to_unknown(
+      env,
+      as_primitive_array::<UInt16Type>(array).value(index) as u32,
+    ),
+    DataType::UInt32 => to_unknown(env, as_primitive_array::<UInt32Type>(array).value(index)),
+    DataType::UInt64 => {
+      let value = as_primitive_array::<UInt64Type>(array).value(index);
+      if int64_as_big_int {
+        to_unknown(env, BigInt::from(value))
+      } else {
+        to_unknown(env, value as f64)
+      }
+    }
+    DataType::Float32 => to_unknown(
+      env,
+      as_primitive_array::<Float32Type>(array).value(index) as f64,
+    ),
+    DataType::Float64 => to_unknown(env, as_primitive_array::<Float64Type>(array).value(index)),
+    DataType::Utf8 => to_unknown(env, as_string_array(array).value(index)),
+    DataType::LargeUtf8 => to_unknown(env, as_largestring_array(array).value(index)),
+    DataType::Binary => to_unknown(
+      env,
+      Buffer::from(as_generic_binary_array::<i32>(array).value(index).to_vec()),
+    ),
+    DataType::LargeBinary => to_unknown(
+      env,
+      Buffer::from(as_generic_binary_array::<i64>(array).value(index).to_vec()),
+    ),
+    _ => to_unknown(
+      env,
+      array_value_to_string(array, index).map_err(anyhow::Error::from)?,
+    ),
+  }
+}
+
+/// The collected [`RecordBatch`]es concatenated into a single batch, converted into
+/// `{ columns, validity }` when passed back to JavaScript.
+pub struct Columns {
+  pub(crate) batch: RecordBatch,
+}
+
+impl ToNapiValue for Columns {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from_raw(env);
+    let mut columns = env.create_object()?;
+    let mut validity = env.create_object()?;
+    let schema = val.batch.schema();
+    for (field, column) in schema.fields().iter().zip(val.batch.columns()) {
+      columns.set_named_property(field.name(), to_js_column(&env, column.as_ref())?)?;
+      match to_validity_bitmap(column.as_ref()) {
+        Some(bitmap) => validity.set_named_property(field.name(), bitmap)?,
+        None => validity.set_named_property(field.name(), Null)?,
+      }
+    }
+    let mut obj = env.create_object()?;
+    obj.set_named_property("columns", columns)?;
+    obj.set_named_property("validity", validity)?;
+    Ok(obj.raw())
+  }
+}
+
+/// Convert the `array` into a `TypedArray` built from its values buffer,
+/// see [`crate::data_frame::DataFrame::collect_columns`] for the type mapping.
+fn to_js_column(env: &Env, array: &dyn Array) -> Result<JsUnknown> {
+  match array.data_type() {
+    DataType::Boolean => {
+      let array = as_boolean_array(array);
+      to_unknown(
+        env,
+        Uint8Array::new((0..array.len()).map(|i| array.value(i) as u8).collect()),
+      )
+    }
+    DataType::Int8 => to_unknown(
+      env,
+      Int8Array::new(as_primitive_array::<Int8Type>(array).values().to_vec()),
+    ),
+    DataType::Int16 => to_unknown(
+      env,
+      Int16Array::new(as_primitive_array::<Int16Type>(array).values().to_vec()),
+    ),
+    DataType::Int32 => to_unknown(
+      env,
+      Int32Array::new(as_primitive_array::<Int32Type>(array).values().to_vec()),
+    ),
+    DataType::Int64 => to_unknown(
+      env,
+      BigInt64Array::new(as_primitive_array::<Int64Type>(array).values().to_vec()),
+    ),
+    DataType::UInt8 => to_unknown(
+      env,
+      Uint8Array::new(as_primitive_array::<UInt8Type>(array).values().to_vec()),
+    ),
+    DataType::UInt16 => to_unknown(
+      env,
+      Uint16Array::new(as_primitive_array::<UInt16Type>(array).values().to_vec()),
+    ),
+    DataType::UInt32 => to_unknown(
+      env,
+      Uint32Array::new(as_primitive_array::<UInt32Type>(array).values().to_vec()),
+    ),
+    DataType::UInt64 => to_unknown(
+      env,
+      BigUint64Array::new(as_primitive_array::<UInt64Type>(array).values().to_vec()),
+    ),
+    DataType::Float32 => to_unknown(
+      env,
+      Float32Array::new(as_primitive_array::<Float32Type>(array).values().to_vec()),
+    ),
+    DataType::Float64 => to_unknown(
+      env,
+      Float64Array::new(as_primitive_array::<Float64Type>(array).values().to_vec()),
+    ),
+    DataType::Date32 => to_unknown(
+      env,
+      Int32Array::new(as_primitive_array::<Date32Type>(array).values().to_vec()),
+    ),
+    DataType::Date64 => to_unknown(
+      env,
+      BigInt64Array::new(as_primitive_array::<Date64Type>(array).values().to_vec()),
+    ),
+    DataType::Timestamp(unit, _) => {
+      let values = match unit {
+        TimeUnit::Second => as_primitive_array::<TimestampSecondType>(array)
+          .values()
+          .to_vec(),
+        TimeUnit::Millisecond => as_primitive_array::<TimestampMillisecondType>(array)
+          .values()
+          .to_vec(),
+        TimeUnit::Microsecond => as_primitive_array::<TimestampMicrosecondType>(array)
+          .values()
+          .to_vec(),
+        TimeUnit::Nanosecond => as_primitive_array::<TimestampNanosecondType>(array)
+          .values()
+          .to_vec(),
+      };
+      to_unknown(env, BigInt64Array::new(values))
+    }
+    DataType::Utf8 => to_unknown(env, as_string_array(array).iter().collect::<Vec<_>>()),
+    DataType::LargeUtf8 => to_unknown(env, as_largestring_array(array).iter().collect::<Vec<_>>()),
+    _ => to_unknown(
+      env,
+      (0..array.len())
+        .map(|i| {
+          if array.is_null(i) {
+            Ok(None)
+          } else {
+            array_value_to_string(array, i).map(Some)
+          }
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(anyhow::Error::from)?,
+    ),
+  }
+}
+
+/// Copy the validity bitmap of `array` out of its null buffer, the same layout as Arrow:
+/// bit `i % 8` of byte `i / 8` is set if the value at `i` is valid.
+/// Return `None` if `array` contains no nulls.
+fn to_validity_bitmap(array: &dyn Array) -> Option<Uint8Array> {
+  let len = (array.len() + 7) / 8;
+  match array.nulls() {
+    Some(nulls) if nulls.null_count() > 0 => {
+      let bitmap = nulls.buffer().bit_slice(nulls.offset(), nulls.len());
+      Some(Uint8Array::new(bitmap.as_slice()[..len].to_vec()))
+    }
+    // `NullArray` has no null buffer, while all of its values are null
+    _ if array.null_count() > 0 => Some(Uint8Array::new(vec![0; len])),
+    _ => None,
+  }
+}
+
+/// Serialize the [`RecordBatch`]es with Arrow IPC in the given `format`.
+pub(crate) fn to_arrow_ipc(
+  schema: &Schema,
+  batches: &[RecordBatch],
+  format: ArrowIpcFormat,
+) -> anyhow::Result<Vec<u8>> {
+  match format {
+    ArrowIpcFormat::Stream => {
+      let mut writer = StreamWriter::try_new(Vec::new(), schema)?;
+      for batch in batches {
+        writer.write(batch)?;
+      }
+      writer.finish()?;
+      Ok(writer.into_inner()?)
+    }
+    ArrowIpcFormat::File => {
+      let mut writer = FileWriter::try_new(Vec::new(), schema)?;
+      for batch in batches {
+        writer.write(batch)?;
+      }
+      writer.finish()?;
+      Ok(writer.into_inner()?)
+    }
+  }
+}
+
+/// Deserialize the Arrow IPC `bytes`, both the streaming format and the file format are accepted.
+pub(crate) fn from_arrow_ipc(bytes: &[u8]) -> anyhow::Result<(SchemaRef, Vec<RecordBatch>)> {
+  // The file format starts with the `ARROW1` magic, while the streaming format starts with a message
+  if bytes.starts_with(b"ARROW1") {
+    let reader = FileReader::try_new(Cursor::new(bytes), None)?;
+    let schema = reader.schema();
+    Ok((schema, reader.collect::<std::result::Result<_, _>>()?))
+  } else {
+    let reader = StreamReader::try_new(Cursor::new(bytes), None)?;
+    let schema = reader.schema();
+    Ok((schema, reader.collect::<std::result::Result<_, _>>()?))
+  }
+}
+
+/// A value read from a JavaScript object
+enum Value {
+  Null,
+  Boolean(bool),
+  Number(f64),
+  BigInt(i64),
+  /// Milliseconds since UNIX epoch of a `Date`
+  Timestamp(i64),
+  String(String),
+}
+
+impl Value {
+  fn from_js(env: &Env, value: JsUnknown) -> Result<Self> {
+    Ok(match value.get_type()? {
+      ValueType::Undefined | ValueType::Null => Value::Null,
+      ValueType::Boolean => Value::Boolean(value.coerce_to_bool()?.get_value()?),
+      ValueType::Number => Value::Number(value.coerce_to_number()?.get_double()?),
+      ValueType::BigInt => match unsafe { value.cast::<JsBigInt>() }.get_i64()? {
+        (v, true) => Value::BigInt(v),
+        (_, false) => {
+          return Err(anyhow::anyhow!("BigInt value is out of the range of Int64").into());
+        }
+      },
+      ValueType::String => Value::String(value.coerce_to_string()?.into_utf8()?.into_owned()?),
+      ValueType::Object if value.is_date()? => {
+        let time = unsafe { value.cast::<JsDate>() }.value_of()?;
+        // Invalid dates have a `NaN` time
+        if time.is_nan() {
+          Value::Null
+        } else {
+          Value::Timestamp(time as i64)
+        }
+      }
+      // Objects and arrays are kept as their JSON representation
+      _ => {
+        let json = env.get_global()?.get_named_property::<JsObject>("JSON")?;
+        let stringify = json.get_named_property::<JsFunction>("stringify")?;
+        Value::String(
+          stringify
+            .call(Some(&json), &[value])?
+            .coerce_to_string()?
+            .into_utf8()?
+            .into_owned()?,
+        )
+      }
+    })
+  }
+
+  fn into_string(self) -> Option<String> {
+    match self {
+      Value::Null => None,
+      Value::Boolean(v) => Some(v.to_string()),
+      Value::Number(v) => Some(v.to_string()),
+      Value::BigInt(v) => Some(v.to_string()),
+      Value::Timestamp(v) => {
+        timestamp_ms_to_datetime(v).map(|t| t.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+      }
+      Value::String(v) => Some(v),
+    }
+  }
+}
+
+/// Build an Arrow array from the `values` of a column, see
+/// [`crate::session_context::SessionContext::register_objects`] for the inferred types.
+fn to_arrow_array(values: Vec<Value>) -> ArrayRef {
+  let (mut booleans, mut numbers, mut integers, mut timestamps, mut others) =
+    (false, false, true, false, false);
+  for value in &values {
+    match value {
+      Value::Null => {}
+      Value::Boolean(_) => booleans = true,
+      Value::Number(v) => {
+        numbers = true;
+        integers &= v.fract() == 0.0 && *v >= i64::MIN as f64 && *v < i64::MAX as f64;
+      }
+      Value::BigInt(_) => numbers = true,
+      Value::Timestamp(_) => timestamps = true,
+      Value::String(_) => others = true,
+    }
+  }
+  match (booleans, numbers, timestamps, others) {
+    (false, false, false, false) => Arc::new(NullArray::new(values.len())),
+    (true, false, false, false) => Arc::new(BooleanArray::from(
+      values
+        .into_iter()
+        .map(|v| match v {
+          Value::Boolean(v) => Some(v),
+          _ => None,
+        })
+        .collect::<Vec<_>>(),
+    )),
+    (false, true, false, false) if integers => Arc::new(Int64Array::from(
+      values
+        .into_iter()
+        .map(|v| match v {
+          Value::Number(v) => Some(v as i64),
+          Value::BigInt(v) => Some(v),
+          _ => None,
+        })
+        .collect::<Vec<_>>(),
+    )),
+    (false, true, false, false) => Arc::new(ArrowFloat64Array::from(
+      values
+        .into_iter()
+        .map(|v| match v {
+          Value::Number(v) => Some(v),
+          Value::BigInt(v) => Some(v as f64),
+          _ => None,
+        })
+        .collect::<Vec<_>>(),
+    )),
+    (false, false, true, false) => Arc::new(TimestampMillisecondArray::from(
+      values
+        .into_iter()
+        .map(|v| match v {
+          Value::Timestamp(v) => Some(v),
+          _ => None,
+        })
+        .collect::<Vec<_>>(),
+    )),
+    _ => Arc::new(StringArray::from(
+      values
+        .into_iter()
+        .map(Value::into_string)
+        .collect::<Vec<_>>(),
+    )),
+  }
+}
+
+/// Build a [`RecordBatch`] from the JavaScript `rows` objects.
+///
+/// Without a `schema`, the columns are the keys of the `rows` in order of their first appearance,
+/// with types inferred by [`to_arrow_array`]. With a `schema`, values are cast into the types of
+/// the schema, and keys not in the schema are ignored.
+pub(crate) fn from_js_rows(
+  env: &Env,
+  rows: Vec<JsObject>,
+  schema: Option<SchemaRef>,
+) -> Result<RecordBatch> {
+  let mut names: Vec<String> = Vec::new();
+  let mut columns: Vec<Vec<Value>> = Vec::new();
+  if let Some(schema) = &schema {
+    names.extend(schema.fields().iter().map(|f| f.name().clone()));
+    columns.resize_with(names.len(), || Vec::with_capacity(rows.len()));
+  }
+  for (row_index, row) in rows.iter().enumerate() {
+    let keys = row.get_property_names()?;
+    for key_index in 0..keys.get_array_length()? {
+      let key = keys
+        .get_element::<JsString>(key_index)?
+        .into_utf8()?
+        .into_owned()?;
+      let column_index = match names.iter().position(|n| n == &key) {
+        Some(index) => index,
+        None if schema.is_none() => {
+          names.push(key.clone());
+          columns.push(Vec::with_capacity(rows.len()));
+          columns.len() - 1
+        }
+        None => continue,
+      };
+      let column = &mut columns[column_index];
+      // Fill the rows missing this key with nulls
+      column.resize_with(row_index, || Value::Null);
+      column.push(Value::from_js(
+        env,
+        row.get_named_property::<JsUnknown>(&key)?,
+      )?);
+    }
+  }
+  let arrays = columns.into_iter().map(|mut column| {
+    column.resize_with(rows.len(), || Value::Null);
+    to_arrow_array(column)
+  });
+  let (schema, arrays) = match schema {
+    Some(schema) => {
+      let arrays = arrays
+        .zip(schema.fields())
+        .map(|(array, field)| match array.data_type() {
+          DataType::Null => Ok(new_null_array(field.data_type(), array.len())),
+          _ => cast(&array, field.data_type()),
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(anyhow::Error::from)?;
+      (schema, arrays)
+    }
+    None => {
+      let arrays = arrays.collect::<Vec<_>>();
+      let fields = names
+        .into_iter()
+        .zip(&arrays)
+        .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+      (Arc::new(Schema::new(fields)), arrays)
+    }
+  };
+  Ok(
+    RecordBatch::try_new_with_options(
+      schema,
+      arrays,
+      &RecordBatchOptions::new().with_row_count(Some(rows.len())),
+    )
+    .map_err(anyhow::Error::from)?,
+  )
+}
+
+#[inline]
+fn to_unknown<T: ToNapiValue>(env: &Env, value: T) -> Result<JsUnknown> {
+  unsafe { JsUnknown::from_raw(env.raw(), T::to_napi_value(env.raw(), value)?) }
+}
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use datafusion::arrow::csv::WriterBuilder as CsvWriterBuilder;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::writer::{
+  write_message, DictionaryTracker, IpcDataGenerator, IpcWriteOptions,
+};
+use datafusion::arrow::json::LineDelimitedWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::physical_plan::{RecordBatchStream as _, SendableRecordBatchStream};
+use futures::StreamExt;
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject, JsUnknown, NapiRaw};
+use napi_derive::napi;
+use tokio::sync::Mutex;
+
+use crate::record_batch::{to_js_rows, CollectOptions};
+
+#[napi(string_enum)]
+/// Format of the chunks of `DataFrame.toReadableStream`
+pub enum ReadableStreamFormat {
+  /// Newline delimited JSON, one line per row
+  #[napi(value = "ndjson")]
+  Ndjson,
+  /// CSV with a header line
+  #[napi(value = "csv")]
+  Csv,
+  /// Arrow IPC streaming format
+  #[napi(value = "arrow")]
+  Arrow,
+  /// Row objects in object mode, see `DataFrame.collect` for the type mapping
+  #[napi(value = "objects")]
+  Objects,
+}
+
+#[napi(object)]
+pub struct ReadableStreamOptions {
+  /// Defaults to `ReadableStreamFormat.Objects`
+  pub format: Option<ReadableStreamFormat>,
+  /// Convert `Int64` and `UInt64` values into `BigInt` in the `objects` format.
+  pub int64_as_big_int: Option<bool>,
+}
+
+enum State {
+  /// The `DataFrame` is executed on the first `next()` call
+  Pending(Box<datafusion::prelude::DataFrame>),
+  Running(SendableRecordBatchStream),
+  Done,
+}
+
+/// Encode the [`RecordBatch`]es into the chunks yielded by the stream
+pub(crate) enum Encoder {
+  Rows(CollectOptions),
+  Ndjson,
+  Csv {
+    with_header: bool,
+  },
+  Arrow {
+    schema_written: bool,
+    dictionary_tracker: DictionaryTracker,
+  },
+}
+
+impl Encoder {
+  pub(crate) fn new(format: ReadableStreamFormat, options: CollectOptions) -> Self {
+    match format {
+      ReadableStreamFormat::Ndjson => Encoder::Ndjson,
+      ReadableStreamFormat::Csv => Encoder::Csv { with_header: true },
+      ReadableStreamFormat::Arrow => Encoder::Arrow {
+        schema_written: false,
+        dictionary_tracker: DictionaryTracker::new(false),
+      },
+      ReadableStreamFormat::Objects => Encoder::Rows(options),
+    }
+  }
+
+  fn encode(&mut self, schema: &SchemaRef, batch: RecordBatch) -> anyhow::Result<Chunk> {
+    let mut bytes = Vec::new();
+    match self {
+      Encoder::Rows(options) => return Ok(Chunk::Rows(batch, *options)),
+      Encoder::Ndjson => {
+        let mut writer = LineDelimitedWriter::new(&mut bytes);
+        writer.write(&batch)?;
+        writer.finish()?;
+      }
+      Encoder::Csv { with_header } => {
+        // The underlying csv writer is buffered, it's flushed into `bytes` when dropped
+        let mut writer = CsvWriterBuilder::new()
+          .has_headers(*with_header)
+          .build(&mut bytes);
+        writer.write(&batch)?;
+        *with_header = false;
+      }
+      Encoder::Arrow {
+        schema_written,
+        dictionary_tracker,
+      } => {
+        let options = IpcWriteOptions::default();
+        let generator = IpcDataGenerator::default();
+        if !*schema_written {
+          write_message(
+            &mut bytes,
+            generator.schema_to_bytes(schema, &options),
+            &options,
+          )?;
+          *schema_written = true;
+        }
+        let (dictionaries, message) =
+          generator.encoded_batch(&batch, dictionary_tracker, &options)?;
+        for dictionary in dictionaries {
+          write_message(&mut bytes, dictionary, &options)?;
+        }
+        write_message(&mut bytes, message, &options)?;
+      }
+    }
+    Ok(Chunk::Bytes(bytes))
+  }
+
+  /// The last chunk after all the batches are encoded, the Arrow IPC streaming format ends with
+  /// an end-of-stream marker.
+  fn finish(&mut self, schema: &SchemaRef) -> anyhow::Result<Option<Chunk>> {
+    match self {
+      Encoder::Arrow { schema_written, .. } => {
+        let mut bytes = Vec::new();
+        if !*schema_written {
+          let options = IpcWriteOptions::default();
+          write_message(
+            &mut bytes,
+            IpcDataGenerator::default().schema_to_bytes(schema, &options),
+            &options,
+          )?;
+        }
+        bytes.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+        Ok(Some(Chunk::Bytes(bytes)))
+      }
+      _ => Ok(None),
+    }
+  }
+}
+
+pub(crate) enum Chunk {
+  Rows(RecordBatch, CollectOptions),
+  Bytes(Vec<u8>),
+}
+
+struct Inner {
+  state: State,
+  encoder: Encoder,
+}
+
+#[napi]
+/// An async iterator over the results of a `DataFrame`, yielding one `RecordBatch` at a time.
+///
+/// Batches are only computed when `next()` is called, so a slow consumer applies backpressure
+/// to the execution instead of having the results buffered in memory.
+pub struct RecordBatchStream {
+  inner: Arc<Mutex<Inner>>,
+}
+
+#[napi]
+impl RecordBatchStream {
+  #[napi(
+    ts_return_type = "Promise<IteratorResult<Array<Record<string, any>> | Buffer, undefined>>"
+  )]
+  /// Poll the next `RecordBatch` and convert it into an `Array` of row objects,
+  /// or a `Buffer` if the stream is created by `DataFrame.toReadableStream` with a byte format.
+  pub async fn next(&self) -> Result<Next> {
+    let mut inner = self.inner.lock().await;
+    let Inner { state, encoder } = &mut *inner;
+    if let State::Pending(df) = std::mem::replace(state, State::Done) {
+      *state = State::Running((*df).execute_stream().await.map_err(anyhow::Error::from)?);
+    }
+    let (schema, batch) = match state {
+      State::Running(stream) => (
+        stream.schema(),
+        stream
+          .next()
+          .await
+          .transpose()
+          .map_err(anyhow::Error::from)?,
+      ),
+      _ => return Ok(Next { chunk: None }),
+    };
+    let chunk = match batch {
+      Some(batch) => Some(encoder.encode(&schema, batch)?),
+      None => {
+        *state = State::Done;
+        encoder.finish(&schema)?
+      }
+    };
+    Ok(Next { chunk })
+  }
+
+  #[napi(
+    js_name = "return",
+    ts_return_type = "Promise<IteratorResult<Array<Record<string, any>> | Buffer, undefined>>"
+  )]
+  /// Stop the execution and release the resources held by the stream,
+  /// called by `for await` when the loop exits early.
+  pub async fn stop(&self) -> Result<Next> {
+    self.inner.lock().await.state = State::Done;
+    Ok(Next { chunk: None })
+  }
+
+  /// Create a `RecordBatchStream` over the results of `df` and make it async iterable.
+  pub(crate) fn create(
+    env: Env,
+    df: datafusion::prelude::DataFrame,
+    encoder: Encoder,
+  ) -> Result<JsObject> {
+    let mut stream = RecordBatchStream {
+      inner: Arc::new(Mutex::new(Inner {
+        state: State::Pending(Box::new(df)),
+        encoder,
+      })),
+    }
+    .into_instance(env)?
+    .as_object(env);
+    let async_iterator = env
+      .get_global()?
+      .get_named_property_unchecked::<JsObject>("Symbol")?
+      .get_named_property::<JsUnknown>("asyncIterator")?;
+    stream.set_property(
+      async_iterator,
+      env.create_function_from_closure("asyncIterator", |ctx| ctx.this::<JsObject>())?,
+    )?;
+    Ok(stream)
+  }
+}
+
+/// The result of `RecordBatchStream.next()`, converted into `{ done, value }` when passed back to JavaScript.
+pub struct Next {
+  chunk: Option<Chunk>,
+}
+
+impl ToNapiValue for Next {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from_raw(env);
+    let mut obj = env.create_object()?;
+    obj.set_named_property("done", val.chunk.is_none())?;
+    match val.chunk {
+      Some(Chunk::Rows(batch, options)) => {
+        obj.set_named_property("value", to_js_rows(&env, &[batch], &options)?)?
+      }
+      Some(Chunk::Bytes(bytes)) => obj.set_named_property("value", Buffer::from(bytes))?,
+      None => obj.set_named_property("value", ())?,
+    }
+    Ok(obj.raw())
+  }
+}
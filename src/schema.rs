@@ -0,0 +1,486 @@
+use napi_derive::napi;
+
+#[napi(string_enum)]
+/// The type of a [`DataType`]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum TypeId {
+  /// Null type
+  Null,
+  /// A boolean type representing the values `true` and `false`
+  Boolean,
+  /// A signed 8-bit integer
+  Int8,
+  /// A signed 16-bit integer
+  Int16,
+  /// A signed 32-bit integer
+  Int32,
+  /// A signed 64-bit integer
+  Int64,
+  /// An unsigned 8-bit integer
+  UInt8,
+  /// An unsigned 16-bit integer
+  UInt16,
+  /// An unsigned 32-bit integer
+  UInt32,
+  /// An unsigned 64-bit integer
+  UInt64,
+  /// A 16-bit floating point number
+  Float16,
+  /// A 32-bit floating point number
+  Float32,
+  /// A 64-bit floating point number
+  Float64,
+  /// A variable-length string in Unicode with UTF-8 encoding
+  Utf8,
+  /// A variable-length string in Unicode with UTF-8 encoding and 64-bit offsets
+  LargeUtf8,
+  /// Opaque binary data of variable length
+  Binary,
+  /// Opaque binary data of variable length and 64-bit offsets
+  LargeBinary,
+  /// Opaque binary data of `size` bytes per value
+  FixedSizeBinary,
+  /// Elapsed time since UNIX epoch in days, 32 bits
+  Date32,
+  /// Elapsed time since UNIX epoch in milliseconds, 64 bits
+  Date64,
+  /// Elapsed time since UNIX epoch in `unit`, with an optional `timezone`
+  Timestamp,
+  /// Elapsed time since midnight in `unit`, either `Second` or `Millisecond`, 32 bits
+  Time32,
+  /// Elapsed time since midnight in `unit`, either `Microsecond` or `Nanosecond`, 64 bits
+  Time64,
+  /// Measure of elapsed time in `unit`, 64 bits
+  Duration,
+  /// Calendar interval in `intervalUnit`
+  Interval,
+  /// Exact 128-bit width decimal value with `precision` and `scale`
+  Decimal128,
+  /// A list of values of the single field in `fields`
+  List,
+  /// A list of values of the single field in `fields` and 64-bit offsets
+  LargeList,
+  /// A list of `size` values of the single field in `fields`
+  FixedSizeList,
+  /// A nested value with `fields`
+  Struct,
+  /// A map of the key and value, which are the two fields in `fields`
+  Map,
+  /// Values of the single field in `fields` encoded by the integer `keyType`
+  Dictionary,
+}
+
+#[napi(string_enum)]
+/// Time unit of `Timestamp`, `Time32`, `Time64` and `Duration`
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+  /// Time in seconds
+  Second,
+  /// Time in milliseconds
+  Millisecond,
+  /// Time in microseconds
+  Microsecond,
+  /// Time in nanoseconds
+  Nanosecond,
+}
+
+impl From<TimeUnit> for datafusion::arrow::datatypes::TimeUnit {
+  fn from(value: TimeUnit) -> Self {
+    match value {
+      TimeUnit::Second => datafusion::arrow::datatypes::TimeUnit::Second,
+      TimeUnit::Millisecond => datafusion::arrow::datatypes::TimeUnit::Millisecond,
+      TimeUnit::Microsecond => datafusion::arrow::datatypes::TimeUnit::Microsecond,
+      TimeUnit::Nanosecond => datafusion::arrow::datatypes::TimeUnit::Nanosecond,
+    }
+  }
+}
+
+impl From<&datafusion::arrow::datatypes::TimeUnit> for TimeUnit {
+  fn from(value: &datafusion::arrow::datatypes::TimeUnit) -> Self {
+    match value {
+      datafusion::arrow::datatypes::TimeUnit::Second => TimeUnit::Second,
+      datafusion::arrow::datatypes::TimeUnit::Millisecond => TimeUnit::Millisecond,
+      datafusion::arrow::datatypes::TimeUnit::Microsecond => TimeUnit::Microsecond,
+      datafusion::arrow::datatypes::TimeUnit::Nanosecond => TimeUnit::Nanosecond,
+    }
+  }
+}
+
+#[napi(string_enum)]
+/// Unit of `Interval`
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum IntervalUnit {
+  /// Number of months
+  YearMonth,
+  /// Number of days and milliseconds
+  DayTime,
+  /// Number of months, days and nanoseconds
+  MonthDayNano,
+}
+
+impl From<IntervalUnit> for datafusion::arrow::datatypes::IntervalUnit {
+  fn from(value: IntervalUnit) -> Self {
+    match value {
+      IntervalUnit::YearMonth => datafusion::arrow::datatypes::IntervalUnit::YearMonth,
+      IntervalUnit::DayTime => datafusion::arrow::datatypes::IntervalUnit::DayTime,
+      IntervalUnit::MonthDayNano => datafusion::arrow::datatypes::IntervalUnit::MonthDayNano,
+    }
+  }
+}
+
+impl From<&datafusion::arrow::datatypes::IntervalUnit> for IntervalUnit {
+  fn from(value: &datafusion::arrow::datatypes::IntervalUnit) -> Self {
+    match value {
+      datafusion::arrow::datatypes::IntervalUnit::YearMonth => IntervalUnit::YearMonth,
+      datafusion::arrow::datatypes::IntervalUnit::DayTime => IntervalUnit::DayTime,
+      datafusion::arrow::datatypes::IntervalUnit::MonthDayNano => IntervalUnit::MonthDayNano,
+    }
+  }
+}
+
+#[napi(object)]
+/// Arrow data type
+///
+/// ```
+/// import { TimeUnit, TypeId } from '@napi-rs/datafusion';
+/// const int64 = { typeId: TypeId.Int64 };
+/// const timestamp = { typeId: TypeId.Timestamp, unit: TimeUnit.Millisecond, timezone: '+00:00' };
+/// const decimal = { typeId: TypeId.Decimal128, precision: 10, scale: 2 };
+/// const list = { typeId: TypeId.List, fields: [{ name: 'item', dataType: { typeId: TypeId.Utf8 } }] };
+/// const map = {
+///   typeId: TypeId.Map,
+///   fields: [
+///     { name: 'key', dataType: { typeId: TypeId.Utf8 }, nullable: false },
+///     { name: 'value', dataType: { typeId: TypeId.Int64 } },
+///   ],
+/// };
+/// const dictionary = {
+///   typeId: TypeId.Dictionary,
+///   keyType: TypeId.Int32,
+///   fields: [{ name: 'value', dataType: { typeId: TypeId.Utf8 } }],
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataType {
+  pub type_id: TypeId,
+  /// Precision of `Decimal128`, required by `Decimal128`
+  pub precision: Option<u32>,
+  /// Scale of `Decimal128`, defaults to `0`
+  pub scale: Option<i32>,
+  /// Unit of `Timestamp`, `Time32`, `Time64` and `Duration`, defaults to `Nanosecond` for `Time64`
+  /// and `Millisecond` for the others
+  pub unit: Option<TimeUnit>,
+  /// Timezone of `Timestamp`
+  pub timezone: Option<String>,
+  /// Unit of `Interval`, defaults to `MonthDayNano`
+  pub interval_unit: Option<IntervalUnit>,
+  /// Number of bytes of `FixedSizeBinary`, or number of values of `FixedSizeList`, required by both
+  pub size: Option<i32>,
+  /// Child fields of `List`, `LargeList`, `FixedSizeList`, `Struct`, `Map` and `Dictionary`
+  pub fields: Option<Vec<Field>>,
+  /// Key type of `Dictionary`, one of the integer types, defaults to `Int32`
+  pub key_type: Option<TypeId>,
+}
+
+impl TryFrom<&DataType> for datafusion::arrow::datatypes::DataType {
+  type Error = anyhow::Error;
+
+  fn try_from(value: &DataType) -> Result<Self, Self::Error> {
+    use datafusion::arrow::datatypes::DataType as ArrowDataType;
+
+    Ok(match value.type_id {
+      TypeId::Null => ArrowDataType::Null,
+      TypeId::Boolean => ArrowDataType::Boolean,
+      TypeId::Int8 => ArrowDataType::Int8,
+      TypeId::Int16 => ArrowDataType::Int16,
+      TypeId::Int32 => ArrowDataType::Int32,
+      TypeId::Int64 => ArrowDataType::Int64,
+      TypeId::UInt8 => ArrowDataType::UInt8,
+      TypeId::UInt16 => ArrowDataType::UInt16,
+      TypeId::UInt32 => ArrowDataType::UInt32,
+      TypeId::UInt64 => ArrowDataType::UInt64,
+      TypeId::Float16 => ArrowDataType::Float16,
+      TypeId::Float32 => ArrowDataType::Float32,
+      TypeId::Float64 => ArrowDataType::Float64,
+      TypeId::Utf8 => ArrowDataType::Utf8,
+      TypeId::LargeUtf8 => ArrowDataType::LargeUtf8,
+      TypeId::Binary => ArrowDataType::Binary,
+      TypeId::LargeBinary => ArrowDataType::LargeBinary,
+      TypeId::FixedSizeBinary => ArrowDataType::FixedSizeBinary(value.size()?),
+      TypeId::Date32 => ArrowDataType::Date32,
+      TypeId::Date64 => ArrowDataType::Date64,
+      TypeId::Timestamp => ArrowDataType::Timestamp(
+        value.unit.unwrap_or(TimeUnit::Millisecond).into(),
+        value.timezone.clone().map(Into::into),
+      ),
+      TypeId::Time32 => match value.unit.unwrap_or(TimeUnit::Millisecond) {
+        unit @ (TimeUnit::Second | TimeUnit::Millisecond) => ArrowDataType::Time32(unit.into()),
+        unit => {
+          return Err(anyhow::anyhow!(
+            "`unit` of Time32 must be Second or Millisecond, got `{unit:?}`"
+          ))
+        }
+      },
+      TypeId::Time64 => match value.unit.unwrap_or(TimeUnit::Nanosecond) {
+        unit @ (TimeUnit::Microsecond | TimeUnit::Nanosecond) => ArrowDataType::Time64(unit.into()),
+        unit => {
+          return Err(anyhow::anyhow!(
+            "`unit` of Time64 must be Microsecond or Nanosecond, got `{unit:?}`"
+          ))
+        }
+      },
+      TypeId::Duration => {
+        ArrowDataType::Duration(value.unit.unwrap_or(TimeUnit::Millisecond).into())
+      }
+      TypeId::Interval => ArrowDataType::Interval(
+        value
+          .interval_unit
+          .unwrap_or(IntervalUnit::MonthDayNano)
+          .into(),
+      ),
+      TypeId::Decimal128 => ArrowDataType::Decimal128(
+        value
+          .precision
+          .ok_or_else(|| anyhow::anyhow!("`precision` is required by Decimal128"))?
+          .try_into()?,
+        value.scale.unwrap_or(0).try_into()?,
+      ),
+      TypeId::List => ArrowDataType::List(value.child(0)?.into()),
+      TypeId::LargeList => ArrowDataType::LargeList(value.child(0)?.into()),
+      TypeId::FixedSizeList => ArrowDataType::FixedSizeList(value.child(0)?.into(), value.size()?),
+      TypeId::Struct => ArrowDataType::Struct(
+        value
+          .fields
+          .iter()
+          .flatten()
+          .map(TryInto::try_into)
+          .collect::<anyhow::Result<Vec<datafusion::arrow::datatypes::Field>>>()?
+          .into(),
+      ),
+      TypeId::Map => {
+        let (key, value) = (value.child(0)?.with_nullable(false), value.child(1)?);
+        let entries = datafusion::arrow::datatypes::Field::new(
+          "entries",
+          ArrowDataType::Struct(vec![key, value].into()),
+          false,
+        );
+        ArrowDataType::Map(entries.into(), false)
+      }
+      TypeId::Dictionary => {
+        let key_type = match value.key_type.unwrap_or(TypeId::Int32) {
+          TypeId::Int8 => ArrowDataType::Int8,
+          TypeId::Int16 => ArrowDataType::Int16,
+          TypeId::Int32 => ArrowDataType::Int32,
+          TypeId::Int64 => ArrowDataType::Int64,
+          TypeId::UInt8 => ArrowDataType::UInt8,
+          TypeId::UInt16 => ArrowDataType::UInt16,
+          TypeId::UInt32 => ArrowDataType::UInt32,
+          TypeId::UInt64 => ArrowDataType::UInt64,
+          key_type => {
+            return Err(anyhow::anyhow!(
+              "`keyType` of Dictionary must be an integer type, got `{key_type:?}`"
+            ))
+          }
+        };
+        ArrowDataType::Dictionary(
+          Box::new(key_type),
+          Box::new(value.child(0)?.data_type().clone()),
+        )
+      }
+    })
+  }
+}
+
+impl TryFrom<&datafusion::arrow::datatypes::DataType> for DataType {
+  type Error = anyhow::Error;
+
+  fn try_from(value: &datafusion::arrow::datatypes::DataType) -> Result<Self, Self::Error> {
+    use datafusion::arrow::datatypes::DataType as ArrowDataType;
+
+    let mut data_type = DataType {
+      type_id: TypeId::Null,
+      precision: None,
+      scale: None,
+      unit: None,
+      timezone: None,
+      interval_unit: None,
+      size: None,
+      fields: None,
+      key_type: None,
+    };
+    data_type.type_id = match value {
+      ArrowDataType::Null => TypeId::Null,
+      ArrowDataType::Boolean => TypeId::Boolean,
+      ArrowDataType::Int8 => TypeId::Int8,
+      ArrowDataType::Int16 => TypeId::Int16,
+      ArrowDataType::Int32 => TypeId::Int32,
+      ArrowDataType::Int64 => TypeId::Int64,
+      ArrowDataType::UInt8 => TypeId::UInt8,
+      ArrowDataType::UInt16 => TypeId::UInt16,
+      ArrowDataType::UInt32 => TypeId::UInt32,
+      ArrowDataType::UInt64 => TypeId::UInt64,
+      ArrowDataType::Float16 => TypeId::Float16,
+      ArrowDataType::Float32 => TypeId::Float32,
+      ArrowDataType::Float64 => TypeId::Float64,
+      ArrowDataType::Utf8 => TypeId::Utf8,
+      ArrowDataType::LargeUtf8 => TypeId::LargeUtf8,
+      ArrowDataType::Binary => TypeId::Binary,
+      ArrowDataType::LargeBinary => TypeId::LargeBinary,
+      ArrowDataType::FixedSizeBinary(size) => {
+        data_type.size = Some(*size);
+        TypeId::FixedSizeBinary
+      }
+      ArrowDataType::Date32 => TypeId::Date32,
+      ArrowDataType::Date64 => TypeId::Date64,
+      ArrowDataType::Timestamp(unit, timezone) => {
+        data_type.unit = Some(unit.into());
+        data_type.timezone = timezone.as_ref().map(|tz| tz.to_string());
+        TypeId::Timestamp
+      }
+      ArrowDataType::Time32(unit) => {
+        data_type.unit = Some(unit.into());
+        TypeId::Time32
+      }
+      ArrowDataType::Time64(unit) => {
+        data_type.unit = Some(unit.into());
+        TypeId::Time64
+      }
+      ArrowDataType::Duration(unit) => {
+        data_type.unit = Some(unit.into());
+        TypeId::Duration
+      }
+      ArrowDataType::Interval(unit) => {
+        data_type.interval_unit = Some(unit.into());
+        TypeId::Interval
+      }
+      ArrowDataType::Decimal128(precision, scale) => {
+        data_type.precision = Some(*precision as u32);
+        data_type.scale = Some(*scale as i32);
+        TypeId::Decimal128
+      }
+      ArrowDataType::List(field) => {
+        data_type.fields = Some(vec![field.as_ref().try_into()?]);
+        TypeId::List
+      }
+      ArrowDataType::LargeList(field) => {
+        data_type.fields = Some(vec![field.as_ref().try_into()?]);
+        TypeId::LargeList
+      }
+      ArrowDataType::FixedSizeList(field, size) => {
+        data_type.fields = Some(vec![field.as_ref().try_into()?]);
+        data_type.size = Some(*size);
+        TypeId::FixedSizeList
+      }
+      ArrowDataType::Struct(fields) => {
+        data_type.fields = Some(
+          fields
+            .iter()
+            .map(|field| field.as_ref().try_into())
+            .collect::<anyhow::Result<_>>()?,
+        );
+        TypeId::Struct
+      }
+      ArrowDataType::Map(entries, _) => {
+        let ArrowDataType::Struct(fields) = entries.data_type() else {
+          return Err(anyhow::anyhow!(
+            "Entries of Map must be a Struct, got `{}`",
+            entries.data_type()
+          ));
+        };
+        data_type.fields = Some(
+          fields
+            .iter()
+            .map(|field| field.as_ref().try_into())
+            .collect::<anyhow::Result<_>>()?,
+        );
+        TypeId::Map
+      }
+      ArrowDataType::Dictionary(key_type, value_type) => {
+        data_type.key_type = Some(DataType::try_from(key_type.as_ref())?.type_id);
+        data_type.fields = Some(vec![Field {
+          name: "value".to_owned(),
+          data_type: value_type.as_ref().try_into()?,
+          nullable: None,
+        }]);
+        TypeId::Dictionary
+      }
+      data_type => {
+        return Err(anyhow::anyhow!(
+          "`{data_type}` is not supported by DataType"
+        ))
+      }
+    };
+    Ok(data_type)
+  }
+}
+
+impl DataType {
+  /// The Arrow field of the child at `index` in `fields`, required by the nested types.
+  fn child(&self, index: usize) -> anyhow::Result<datafusion::arrow::datatypes::Field> {
+    self
+      .fields
+      .as_ref()
+      .and_then(|fields| fields.get(index))
+      .ok_or_else(|| {
+        anyhow::anyhow!(
+          "`fields` of {:?} must have at least {} field(s)",
+          self.type_id,
+          index + 1
+        )
+      })?
+      .try_into()
+  }
+
+  /// The `size` of the fixed size types, required by `FixedSizeBinary` and `FixedSizeList`.
+  fn size(&self) -> anyhow::Result<i32> {
+    self
+      .size
+      .ok_or_else(|| anyhow::anyhow!("`size` is required by {:?}", self.type_id))
+  }
+}
+
+#[napi(object)]
+/// A field of a schema, which is a named column with its data type
+#[derive(Debug, Clone)]
+pub struct Field {
+  pub name: String,
+  pub data_type: DataType,
+  /// Defaults to `true`
+  pub nullable: Option<bool>,
+}
+
+impl TryFrom<&Field> for datafusion::arrow::datatypes::Field {
+  type Error = anyhow::Error;
+
+  fn try_from(value: &Field) -> Result<Self, Self::Error> {
+    Ok(datafusion::arrow::datatypes::Field::new(
+      &value.name,
+      (&value.data_type).try_into()?,
+      value.nullable.unwrap_or(true),
+    ))
+  }
+}
+
+impl TryFrom<&datafusion::arrow::datatypes::Field> for Field {
+  type Error = anyhow::Error;
+
+  fn try_from(value: &datafusion::arrow::datatypes::Field) -> Result<Self, Self::Error> {
+    Ok(Field {
+      name: value.name().clone(),
+      data_type: value.data_type().try_into()?,
+      nullable: Some(value.is_nullable()),
+    })
+  }
+}
+
+/// Create an Arrow schema from the `fields`.
+pub(crate) fn to_arrow_schema(
+  fields: &[Field],
+) -> anyhow::Result<datafusion::arrow::datatypes::Schema> {
+  Ok(datafusion::arrow::datatypes::Schema::new(
+    fields
+      .iter()
+      .map(TryInto::try_into)
+      .collect::<anyhow::Result<Vec<_>>>()?,
+  ))
+}
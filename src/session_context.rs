@@ -1,7 +1,21 @@
-use datafusion::prelude::CsvReadOptions;
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::datasource::MemTable;
+use napi::bindgen_prelude::Buffer;
+use napi::{Env, JsObject};
 use napi_derive::napi;
 
 use crate::data_frame::DataFrame;
+use crate::reader::{CsvReadOptions, NdJsonReadOptions, ParquetReadOptions};
+use crate::record_batch::{from_arrow_ipc, from_js_rows};
+use crate::schema::{to_arrow_schema, Field};
+
+#[napi(object)]
+pub struct RegisterObjectsOptions {
+  /// The schema of the table, inferred from the values if not provided
+  pub schema: Option<Vec<Field>>,
+}
 
 #[napi]
 pub struct SessionContext {
@@ -34,17 +48,318 @@ impl SessionContext {
   /// default implementations.
   pub async fn sql(&self, sql: String) -> Result<DataFrame, napi::Error> {
     let df = self.inner.sql(&sql).await.map_err(anyhow::Error::from)?;
-    Ok(DataFrame { inner: Some(df) })
+    Ok(DataFrame { inner: df })
+  }
+
+  #[napi]
+  /// Creates a [`DataFrame`] for reading a CSV data source, `path` can be a single file
+  /// or a directory of files.
+  ///
+  /// ```
+  /// import { SessionContext, TypeId } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('data/users.csv', {
+  ///   hasHeader: false,
+  ///   delimiter: ';',
+  ///   schema: [
+  ///     { name: 'id', dataType: { typeId: TypeId.Int64 } },
+  ///     { name: 'name', dataType: { typeId: TypeId.Utf8 } },
+  ///   ],
+  /// });
+  /// ```
+  pub async fn read_csv(
+    &self,
+    path: String,
+    options: Option<CsvReadOptions>,
+  ) -> Result<DataFrame, napi::Error> {
+    let options = options.unwrap_or_default();
+    let schema = options.schema()?;
+    let df = self
+      .inner
+      .read_csv(path, options.to_options(schema.as_ref())?)
+      .await
+      .map_err(anyhow::Error::from)?;
+    Ok(DataFrame { inner: df })
+  }
+
+  #[napi]
+  /// Registers a CSV data source as a table that can be referenced from SQL statements
+  /// executed against this context.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// await ctx.registerCsv('example', 'tests/data/example.csv');
+  /// const df = await ctx.sql('SELECT a, b FROM example');
+  /// ```
+  pub async fn register_csv(
+    &self,
+    name: String,
+    path: String,
+    options: Option<CsvReadOptions>,
+  ) -> Result<(), napi::Error> {
+    let options = options.unwrap_or_default();
+    let schema = options.schema()?;
+    self
+      .inner
+      .register_csv(&name, &path, options.to_options(schema.as_ref())?)
+      .await
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  /// Creates a [`DataFrame`] for reading a Parquet data source, `path` can be a single file
+  /// or a directory of files.
+  ///
+  /// ```
+  /// import { SessionContext, TypeId } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readParquet('data/events', {
+  ///   tablePartitionCols: [{ name: 'year', dataType: { typeId: TypeId.Int32 } }],
+  /// });
+  /// ```
+  pub async fn read_parquet(
+    &self,
+    path: String,
+    options: Option<ParquetReadOptions>,
+  ) -> Result<DataFrame, napi::Error> {
+    let options = options.unwrap_or_default();
+    let df = self
+      .inner
+      .read_parquet(path, options.to_options()?)
+      .await
+      .map_err(anyhow::Error::from)?;
+    Ok(DataFrame { inner: df })
   }
 
   #[napi]
-  /// Creates a [`DataFrame`] for reading a CSV data source.
-  pub async fn read_csv(&self, path: String) -> Result<DataFrame, napi::Error> {
+  /// Registers a Parquet data source as a table that can be referenced from SQL statements
+  /// executed against this context.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// await ctx.registerParquet('events', 'data/events', { fileExtension: '.pq' });
+  /// const df = await ctx.sql('SELECT count(*) FROM events');
+  /// ```
+  pub async fn register_parquet(
+    &self,
+    name: String,
+    path: String,
+    options: Option<ParquetReadOptions>,
+  ) -> Result<(), napi::Error> {
+    let options = options.unwrap_or_default();
+    self
+      .inner
+      .register_parquet(&name, &path, options.to_options()?)
+      .await
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  /// Creates a [`DataFrame`] for reading a newline delimited JSON data source, `path` can be a single file
+  /// or a directory of files.
+  ///
+  /// ```
+  /// import { FileCompressionType, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readJson('logs/app.json.gz', {
+  ///   fileExtension: '.json.gz',
+  ///   fileCompressionType: FileCompressionType.Gzip,
+  /// });
+  /// ```
+  pub async fn read_json(
+    &self,
+    path: String,
+    options: Option<NdJsonReadOptions>,
+  ) -> Result<DataFrame, napi::Error> {
+    let options = options.unwrap_or_default();
+    let schema = options.schema()?;
     let df = self
       .inner
-      .read_csv(path, CsvReadOptions::default())
+      .read_json(path, options.to_options(schema.as_ref())?)
       .await
       .map_err(anyhow::Error::from)?;
-    Ok(DataFrame { inner: Some(df) })
+    Ok(DataFrame { inner: df })
   }
+
+  #[napi]
+  /// Registers a newline delimited JSON data source as a table that can be referenced from SQL statements
+  /// executed against this context.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// await ctx.registerJson('logs', 'logs', { schemaInferMaxRecords: 100 });
+  /// const df = await ctx.sql("SELECT count(*) FROM logs WHERE level = 'error'");
+  /// ```
+  pub async fn register_json(
+    &self,
+    name: String,
+    path: String,
+    options: Option<NdJsonReadOptions>,
+  ) -> Result<(), napi::Error> {
+    let options = options.unwrap_or_default();
+    let schema = options.schema()?;
+    self
+      .inner
+      .register_json(&name, &path, options.to_options(schema.as_ref())?)
+      .await
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi]
+  /// Creates a [`DataFrame`] from an Arrow IPC `Buffer`, in either the streaming format or the file format.
+  ///
+  /// ```
+  /// import { tableToIPC } from 'apache-arrow';
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = ctx.readArrowIpc(Buffer.from(tableToIPC(table, 'stream')));
+  /// ```
+  pub fn read_arrow_ipc(&self, buffer: Buffer) -> Result<DataFrame, napi::Error> {
+    let df = self
+      .inner
+      .read_table(Arc::new(arrow_ipc_table(&buffer)?))
+      .map_err(anyhow::Error::from)?;
+    Ok(DataFrame { inner: df })
+  }
+
+  #[napi]
+  /// Registers an Arrow IPC `Buffer`, in either the streaming format or the file format,
+  /// as a table that can be referenced from SQL statements executed against this context.
+  ///
+  /// ```
+  /// import { tableToIPC } from 'apache-arrow';
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// ctx.registerArrowIpc('example', Buffer.from(tableToIPC(table, 'stream')));
+  /// const df = await ctx.sql('SELECT * FROM example');
+  /// ```
+  pub fn register_arrow_ipc(&self, name: String, buffer: Buffer) -> Result<(), napi::Error> {
+    self
+      .inner
+      .register_table(name.as_str(), Arc::new(arrow_ipc_table(&buffer)?))
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi(ts_return_type = "Promise<void>")]
+  /// Executes `df` and registers the results as an in-memory table that can be referenced
+  /// from SQL statements executed against this context.
+  ///
+  /// Unlike `registerView`, `df` is executed once, and later changes of its sources are not visible.
+  ///
+  /// ```
+  /// import { col, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// await ctx.registerTable('example', df.filter(col('a').ltEq(col('b'))));
+  /// const result = await ctx.sql('SELECT count(*) FROM example');
+  /// ```
+  pub fn register_table(
+    &self,
+    env: Env,
+    name: String,
+    df: &DataFrame,
+  ) -> Result<JsObject, napi::Error> {
+    let ctx = self.inner.clone();
+    let df = df.value();
+    env.execute_tokio_future(
+      async move {
+        let schema: SchemaRef = Arc::new(df.schema().into());
+        let partitions = df
+          .collect_partitioned()
+          .await
+          .map_err(anyhow::Error::from)?;
+        // The physical plan may differ from the logical schema in nullability or metadata
+        let schema = partitions
+          .iter()
+          .flatten()
+          .next()
+          .map(|batch| batch.schema())
+          .unwrap_or(schema);
+        let table = MemTable::try_new(schema, partitions).map_err(anyhow::Error::from)?;
+        ctx
+          .register_table(name.as_str(), Arc::new(table))
+          .map_err(anyhow::Error::from)?;
+        Ok(())
+      },
+      |env, _| env.get_undefined(),
+    )
+  }
+
+  #[napi]
+  /// Registers `df` as a view that can be referenced from SQL statements executed against
+  /// this context, `df` is executed every time the view is queried.
+  ///
+  /// ```
+  /// import { col, SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// const df = await ctx.readCsv('tests/data/example.csv');
+  /// ctx.registerView('example', df.filter(col('a').ltEq(col('b'))));
+  /// const result = await ctx.sql('SELECT count(*) FROM example');
+  /// ```
+  pub fn register_view(&self, name: String, df: &DataFrame) -> Result<(), napi::Error> {
+    self
+      .inner
+      .register_table(name.as_str(), df.value().into_view())
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+
+  #[napi(
+    ts_args_type = "name: string, rows: Array<Record<string, any>>, options?: RegisterObjectsOptions | undefined | null"
+  )]
+  /// Registers an `Array` of plain objects as a table that can be referenced from SQL statements
+  /// executed against this context.
+  ///
+  /// Without a `schema`, the columns are the keys of the objects, and their types are inferred from the values:
+  ///
+  /// - only `null` and `undefined` => `Null`
+  /// - `boolean` => `Boolean`
+  /// - integral `number` and `BigInt` => `Int64`
+  /// - `number` and `BigInt` => `Float64`
+  /// - `Date` => `Timestamp` in milliseconds without timezone
+  /// - otherwise => `Utf8`, objects and arrays are converted into JSON strings, dates into ISO 8601 strings
+  ///
+  /// `BigInt` values out of the range of `Int64` are rejected.
+  ///
+  /// With a `schema`, the values are cast into the types of the schema.
+  ///
+  /// ```
+  /// import { SessionContext } from '@napi-rs/datafusion';
+  /// const ctx = new SessionContext();
+  /// ctx.registerObjects('users', [{ id: 1, name: 'Alice' }, { id: 2, name: 'Bob' }]);
+  /// const df = await ctx.sql('SELECT name FROM users WHERE id = 1');
+  /// ```
+  pub fn register_objects(
+    &self,
+    env: Env,
+    name: String,
+    rows: Vec<JsObject>,
+    options: Option<RegisterObjectsOptions>,
+  ) -> Result<(), napi::Error> {
+    let schema = options
+      .and_then(|o| o.schema)
+      .map(|fields| to_arrow_schema(&fields))
+      .transpose()?
+      .map(Arc::new);
+    let batch = from_js_rows(&env, rows, schema)?;
+    let table =
+      MemTable::try_new(batch.schema(), vec![vec![batch]]).map_err(anyhow::Error::from)?;
+    self
+      .inner
+      .register_table(name.as_str(), Arc::new(table))
+      .map_err(anyhow::Error::from)?;
+    Ok(())
+  }
+}
+
+fn arrow_ipc_table(bytes: &[u8]) -> Result<MemTable, napi::Error> {
+  let (schema, batches) = from_arrow_ipc(bytes)?;
+  Ok(MemTable::try_new(schema, vec![batches]).map_err(anyhow::Error::from)?)
 }
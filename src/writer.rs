@@ -0,0 +1,197 @@
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use datafusion::arrow::csv::WriterBuilder as CsvWriterBuilder;
+use datafusion::arrow::json::LineDelimitedWriter;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::parquet::basic::{BrotliLevel, Compression, GzipLevel, ZstdLevel};
+use datafusion::parquet::file::properties::WriterProperties;
+use datafusion::physical_plan::{RecordBatchStream as _, SendableRecordBatchStream};
+use futures::StreamExt;
+use napi_derive::napi;
+
+use crate::options::to_byte;
+
+#[napi(string_enum)]
+/// Compression codec of Parquet files
+pub enum ParquetCompression {
+  /// No compression
+  #[napi(value = "uncompressed")]
+  Uncompressed,
+  /// Snappy compression
+  #[napi(value = "snappy")]
+  Snappy,
+  /// Gzip compression with the default level
+  #[napi(value = "gzip")]
+  Gzip,
+  /// Brotli compression with the default level
+  #[napi(value = "brotli")]
+  Brotli,
+  /// LZ4 compression without the Hadoop framing
+  #[napi(value = "lz4_raw")]
+  Lz4Raw,
+  /// Zstandard compression with the default level
+  #[napi(value = "zstd")]
+  Zstd,
+}
+
+impl From<ParquetCompression> for Compression {
+  fn from(value: ParquetCompression) -> Self {
+    match value {
+      ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+      ParquetCompression::Snappy => Compression::SNAPPY,
+      ParquetCompression::Gzip => Compression::GZIP(GzipLevel::default()),
+      ParquetCompression::Brotli => Compression::BROTLI(BrotliLevel::default()),
+      ParquetCompression::Lz4Raw => Compression::LZ4_RAW,
+      ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+    }
+  }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct ParquetWriteOptions {
+  /// Defaults to `ParquetCompression.Uncompressed`
+  pub compression: Option<ParquetCompression>,
+  /// Maximum number of rows in a row group, defaults to `1048576`
+  pub max_row_group_size: Option<u32>,
+  /// Write all the results into the single file at `path`, or one file per partition
+  /// into the directory at `path`. Defaults to `true`
+  pub single_file_output: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct CsvWriteOptions {
+  /// Defaults to `,`
+  pub delimiter: Option<String>,
+  /// Write the column names as the first line, defaults to `true`
+  pub has_header: Option<bool>,
+  /// Write all the results into the single file at `path`, or one file per partition
+  /// into the directory at `path`. Defaults to `true`
+  pub single_file_output: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct JsonWriteOptions {
+  /// Write all the results into the single file at `path`, or one file per partition
+  /// into the directory at `path`. Defaults to `true`
+  pub single_file_output: Option<bool>,
+}
+
+#[derive(Clone)]
+pub(crate) enum FileFormat {
+  Parquet(WriterProperties),
+  Csv { delimiter: u8, has_header: bool },
+  Json,
+}
+
+impl FileFormat {
+  fn extension(&self) -> &'static str {
+    match self {
+      FileFormat::Parquet(_) => "parquet",
+      FileFormat::Csv { .. } => "csv",
+      FileFormat::Json => "json",
+    }
+  }
+}
+
+impl From<ParquetWriteOptions> for FileFormat {
+  fn from(value: ParquetWriteOptions) -> Self {
+    let mut builder = WriterProperties::builder();
+    if let Some(compression) = value.compression {
+      builder = builder.set_compression(compression.into());
+    }
+    if let Some(max_row_group_size) = value.max_row_group_size {
+      builder = builder.set_max_row_group_size(max_row_group_size as usize);
+    }
+    FileFormat::Parquet(builder.build())
+  }
+}
+
+impl TryFrom<CsvWriteOptions> for FileFormat {
+  type Error = anyhow::Error;
+
+  fn try_from(value: CsvWriteOptions) -> Result<Self, Self::Error> {
+    Ok(FileFormat::Csv {
+      delimiter: value
+        .delimiter
+        .as_deref()
+        .map(|d| to_byte("delimiter", d))
+        .transpose()?
+        .unwrap_or(b','),
+      has_header: value.has_header.unwrap_or(true),
+    })
+  }
+}
+
+/// Execute `df` and write the results in `format`, into the single file at `path` if
+/// `single_file_output` is `true`, otherwise into `part-{i}.{ext}` files under the directory at
+/// `path`, one file per output partition.
+pub(crate) async fn write(
+  df: datafusion::prelude::DataFrame,
+  path: &str,
+  format: FileFormat,
+  single_file_output: bool,
+) -> anyhow::Result<()> {
+  let (streams, paths): (Vec<_>, Vec<_>) = if single_file_output {
+    (vec![df.execute_stream().await?], vec![PathBuf::from(path)])
+  } else {
+    fs::create_dir_all(path)?;
+    let streams = df.execute_stream_partitioned().await?;
+    let paths = (0..streams.len())
+      .map(|i| Path::new(path).join(format!("part-{i}.{}", format.extension())))
+      .collect();
+    (streams, paths)
+  };
+  let tasks = streams
+    .into_iter()
+    .zip(paths)
+    .map(|(stream, path)| {
+      let format = format.clone();
+      tokio::spawn(async move { write_stream(stream, &path, format).await })
+    })
+    .collect::<Vec<_>>();
+  for task in tasks {
+    task.await??;
+  }
+  Ok(())
+}
+
+async fn write_stream(
+  mut stream: SendableRecordBatchStream,
+  path: &Path,
+  format: FileFormat,
+) -> anyhow::Result<()> {
+  let file = File::create(path)?;
+  match format {
+    FileFormat::Parquet(properties) => {
+      let mut writer = ArrowWriter::try_new(file, stream.schema(), Some(properties))?;
+      while let Some(batch) = stream.next().await.transpose()? {
+        writer.write(&batch)?;
+      }
+      writer.close()?;
+    }
+    FileFormat::Csv {
+      delimiter,
+      has_header,
+    } => {
+      let mut writer = CsvWriterBuilder::new()
+        .with_delimiter(delimiter)
+        .has_headers(has_header)
+        .build(file);
+      while let Some(batch) = stream.next().await.transpose()? {
+        writer.write(&batch)?;
+      }
+    }
+    FileFormat::Json => {
+      let mut writer = LineDelimitedWriter::new(file);
+      while let Some(batch) = stream.next().await.transpose()? {
+        writer.write(&batch)?;
+      }
+      writer.finish()?;
+    }
+  }
+  Ok(())
+}